@@ -0,0 +1,133 @@
+use crate::linalg::symmetric_eigen;
+
+use super::{Matrix3, Matrix4, Quaternion, Vector3};
+
+/// Returns the rigid transform that best maps the points in `src` onto the
+/// matching points in `dst` in the least-squares sense.
+///
+/// The result is a rotation `q` and a translation `t` such that
+/// `dst[i] ≈ q * src[i] + t` for every `i`, i.e. the rotation is applied
+/// first. This is useful for aligning scans or anchoring content to tracked
+/// features.
+///
+/// The rotation is computed with Horn's closed-form quaternion method. See
+/// [Horn, "Closed-form solution of absolute orientation using unit
+/// quaternions"][horn] (1987).
+///
+/// If there are no points, the identity rotation and zero translation are
+/// returned.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// [horn]: https://doi.org/10.1364/JOSAA.4.000629
+pub fn best_fit_transform(src: &[Vector3], dst: &[Vector3]) -> (Quaternion, Vector3) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "point sets must have the same number of points"
+    );
+
+    if src.is_empty() {
+        return (Quaternion::default(), Vector3::default());
+    }
+
+    let n = src.len() as f32;
+    let src_centroid = src.iter().fold(Vector3::default(), |acc, p| acc + p) / n;
+    let dst_centroid = dst.iter().fold(Vector3::default(), |acc, p| acc + p) / n;
+
+    // Cross-covariance matrix of the centered point sets.
+    let mut s = [[0.0f64; 3]; 3];
+
+    for (a, b) in src.iter().zip(dst) {
+        let a = a - src_centroid;
+        let b = b - dst_centroid;
+        let a = [a.x as f64, a.y as f64, a.z as f64];
+        let b = [b.x as f64, b.y as f64, b.z as f64];
+
+        for (row, a) in s.iter_mut().zip(a) {
+            for (element, b) in row.iter_mut().zip(b) {
+                *element += a * b;
+            }
+        }
+    }
+
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+
+    #[rustfmt::skip]
+    let n_matrix = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+
+    // The optimal rotation is the eigenvector of the largest eigenvalue.
+    let (values, vectors) = symmetric_eigen(n_matrix);
+    let largest = (0..4)
+        .max_by(|&i, &j| values[i].total_cmp(&values[j]))
+        .unwrap_or_default();
+    let [w, x, y, z] = vectors[largest];
+
+    let mut rotation = Quaternion {
+        x: x as f32,
+        y: y as f32,
+        z: z as f32,
+        w: w as f32,
+    };
+    rotation.normalize();
+
+    let rotation_matrix = Matrix3::from_matrix4(&Matrix4::from_quaternion(&rotation));
+    let translation = dst_centroid - rotation_matrix * src_centroid;
+
+    (rotation, translation)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_best_fit_transform() {
+        let src: [Vector3; 4] = [
+            (0.0, 0.0, 0.0).into(),
+            (1.0, 0.0, 0.0).into(),
+            (0.0, 2.0, 0.0).into(),
+            (0.0, 0.0, 3.0).into(),
+        ];
+
+        let mut rotation = Quaternion::from_axis_angle(&(1.0, 2.0, 3.0).into(), 1.2);
+        rotation.normalize();
+        let translation = Vector3 {
+            x: 4.0,
+            y: -5.0,
+            z: 6.0,
+        };
+
+        let rotation_matrix = Matrix3::from_matrix4(&Matrix4::from_quaternion(&rotation));
+        let dst = src.map(|p| rotation_matrix * p + translation);
+
+        let (q, t) = best_fit_transform(&src, &dst);
+        let sign = if q.w * rotation.w < 0.0 { -1.0 } else { 1.0 };
+
+        assert_float_absolute_eq!(q.x * sign, rotation.x, 1e-5);
+        assert_float_absolute_eq!(q.y * sign, rotation.y, 1e-5);
+        assert_float_absolute_eq!(q.z * sign, rotation.z, 1e-5);
+        assert_float_absolute_eq!(q.w * sign, rotation.w, 1e-5);
+
+        assert_float_absolute_eq!(t.x, translation.x, 1e-5);
+        assert_float_absolute_eq!(t.y, translation.y, 1e-5);
+        assert_float_absolute_eq!(t.z, translation.z, 1e-5);
+    }
+
+    #[test]
+    fn test_best_fit_transform_empty() {
+        let (q, t) = best_fit_transform(&[], &[]);
+
+        assert_eq!(q, Quaternion::default());
+        assert_eq!(t, Vector3::default());
+    }
+}
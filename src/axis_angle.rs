@@ -0,0 +1,236 @@
+use std::{fmt, ops, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+
+use super::{Matrix3, ParseError, Quaternion, Vector3};
+
+/// Rotation by an angle around an axis.
+///
+/// Robotics and computer vision pipelines often exchange rotations as
+/// rotation vectors, whose direction is the axis and whose length is the
+/// angle. See [`Self::from_rotation_vector`] and [`Self::to_rotation_vector`].
+///
+/// You can convert between axis-angle rotations, [`Quaternion`]s, and rotation
+/// [`Matrix3`]s using `.into()` or `from`.
+///
+/// ## Supported operators
+///
+/// - [`ops::Add`]: `a + b` is the rotation obtained by first applying `a` and
+///   then `b`, i.e. `a.then(&b)`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct AxisAngle {
+    /// The normalized rotation axis.
+    pub axis: Vector3,
+    /// The rotation angle in radians.
+    pub angle: f32,
+}
+
+unsafe impl Send for AxisAngle {}
+unsafe impl Sync for AxisAngle {}
+
+impl Default for AxisAngle {
+    /// Returns no rotation, i.e. a zero angle around the X axis.
+    fn default() -> Self {
+        Self {
+            axis: (1.0, 0.0, 0.0).into(),
+            angle: 0.0,
+        }
+    }
+}
+
+impl fmt::Display for AxisAngle {
+    /// Formats this rotation as `(x, y, z, angle)`, where `(x, y, z)` is the
+    /// axis.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.axis.x, self.axis.y, self.axis.z, self.angle])
+    }
+}
+
+impl FromStr for AxisAngle {
+    type Err = ParseError;
+
+    /// Parses a rotation in the format written by `Display`, e.g.
+    /// `(x, y, z, angle)`. The parentheses are optional. The axis is used as
+    /// is, i.e. it is not normalized.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z, angle] = parse_components::<f32, 4>(s)?;
+
+        Ok(Self {
+            axis: Vector3 { x, y, z },
+            angle,
+        })
+    }
+}
+
+impl From<&Quaternion> for AxisAngle {
+    /// Converts the given rotation quaternion to an axis-angle rotation with
+    /// an angle between `0.0` and `π`.
+    fn from(q: &Quaternion) -> Self {
+        let sign = if q.w < 0.0 { -1.0 } else { 1.0 };
+        let v = Vector3 {
+            x: q.x * sign,
+            y: q.y * sign,
+            z: q.z * sign,
+        };
+
+        let sin = v.length();
+
+        if sin == 0.0 {
+            return Self::default();
+        }
+
+        Self {
+            axis: v / sin,
+            angle: 2.0 * sin.atan2(q.w * sign),
+        }
+    }
+}
+
+impl From<&AxisAngle> for Quaternion {
+    /// Converts the given axis-angle rotation to a rotation quaternion.
+    fn from(axis_angle: &AxisAngle) -> Self {
+        Quaternion::from_axis_angle(&axis_angle.axis, axis_angle.angle)
+    }
+}
+
+impl From<&Matrix3> for AxisAngle {
+    /// Converts the given rotation matrix to an axis-angle rotation.
+    fn from(m: &Matrix3) -> Self {
+        Self::from(&Quaternion::from_rotation_matrix(m))
+    }
+}
+
+impl From<&AxisAngle> for Matrix3 {
+    /// Converts the given axis-angle rotation to a rotation matrix.
+    fn from(axis_angle: &AxisAngle) -> Self {
+        Matrix3::from_rodrigues(&axis_angle.to_rotation_vector())
+    }
+}
+
+impl_op_ex!(+|a: &AxisAngle, b: &AxisAngle| -> AxisAngle { a.then(b) });
+
+impl AxisAngle {
+    /// Creates a new rotation by the given angle in radians around the given
+    /// axis. The axis must be normalized.
+    pub fn new(axis: Vector3, angle: f32) -> Self {
+        Self { axis, angle }
+    }
+
+    /// Creates a new rotation from the given rotation vector, whose direction
+    /// is the rotation axis and whose length is the rotation angle in radians.
+    pub fn from_rotation_vector(v: &Vector3) -> Self {
+        let angle = v.length();
+
+        if angle == 0.0 {
+            return Self::default();
+        }
+
+        Self {
+            axis: v / angle,
+            angle,
+        }
+    }
+
+    /// Returns the rotation vector, whose direction is the rotation axis and
+    /// whose length is the rotation angle in radians.
+    pub fn to_rotation_vector(&self) -> Vector3 {
+        self.axis * self.angle
+    }
+
+    /// Returns the rotation obtained by first applying this rotation and then
+    /// `other`. This is the same as `self + other`.
+    pub fn then(&self, other: &Self) -> Self {
+        Self::from(&(Quaternion::from(other) * Quaternion::from(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    fn axis_angle_equals(a: AxisAngle, b: AxisAngle) {
+        assert_float_absolute_eq!(a.axis.x, b.axis.x);
+        assert_float_absolute_eq!(a.axis.y, b.axis.y);
+        assert_float_absolute_eq!(a.axis.z, b.axis.z);
+        assert_float_absolute_eq!(a.angle, b.angle);
+    }
+
+    #[test]
+    fn test_rotation_vector() {
+        let v = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: -2.0,
+        };
+
+        let r = AxisAngle::from_rotation_vector(&v);
+
+        assert_eq!(r, AxisAngle::new((0.0, 0.0, -1.0).into(), 2.0));
+        assert_eq!(r.to_rotation_vector(), v);
+        assert_eq!(
+            AxisAngle::from_rotation_vector(&Vector3::default()),
+            AxisAngle::default()
+        );
+    }
+
+    #[test]
+    fn test_quaternion_conversion() {
+        let r = AxisAngle::new((0.0, 0.6, 0.8).into(), 1.5);
+
+        let q = Quaternion::from(&r);
+        axis_angle_equals(AxisAngle::from(&q), r);
+
+        let negated = Quaternion {
+            x: -q.x,
+            y: -q.y,
+            z: -q.z,
+            w: -q.w,
+        };
+        axis_angle_equals(AxisAngle::from(&negated), r);
+
+        assert_eq!(
+            AxisAngle::from(&Quaternion::default()),
+            AxisAngle::default()
+        );
+    }
+
+    #[test]
+    fn test_matrix3_conversion() {
+        let r = AxisAngle::new((0.6, 0.0, -0.8).into(), 2.5);
+
+        let m = Matrix3::from(&r);
+        axis_angle_equals(AxisAngle::from(&m), r);
+    }
+
+    #[test]
+    fn test_add() {
+        let a = AxisAngle::new((0.0, 0.0, 1.0).into(), FRAC_PI_2);
+        let b = AxisAngle::new((1.0, 0.0, 0.0).into(), FRAC_PI_2);
+
+        // Rotating X around Z, then around X, ends up on Z.
+        let v = Matrix3::from(&(a + b)) * Vector3::from((1.0, 0.0, 0.0));
+
+        assert_float_absolute_eq!(v.x, 0.0);
+        assert_float_absolute_eq!(v.y, 0.0);
+        assert_float_absolute_eq!(v.z, 1.0);
+
+        axis_angle_equals(a + AxisAngle::default(), a);
+        axis_angle_equals(a.then(&b), a + b);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let a = AxisAngle::new((0.0, 1.0, 0.0).into(), 0.5);
+
+        assert_eq!(a.to_string(), "(0, 1, 0, 0.5)");
+        assert_eq!(a.to_string().parse(), Ok(a));
+        assert!("(0, 1, 0)".parse::<AxisAngle>().is_err());
+    }
+}
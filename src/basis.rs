@@ -0,0 +1,199 @@
+use super::{Matrix3, Quaternion, Vector3};
+
+/// Handedness of a coordinate frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    /// `x × y = z`, the convention used throughout this library.
+    Right,
+    /// `x × y = -z`, e.g. a mirrored frame.
+    Left,
+}
+
+unsafe impl Send for Handedness {}
+unsafe impl Sync for Handedness {}
+
+/// Orthonormal frame of three axes, e.g. the tangent frame of a surface
+/// point.
+///
+/// A basis is expected to be orthonormal and right-handed, in which case it
+/// represents a rotation: the axes are where the rotation takes the X, Y, and
+/// Z axes. Use [`Basis::try_new`] to validate a basis built from arbitrary
+/// axes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Basis {
+    /// The X axis, e.g. the tangent.
+    pub x: Vector3,
+    /// The Y axis, e.g. the bitangent.
+    pub y: Vector3,
+    /// The Z axis, e.g. the normal.
+    pub z: Vector3,
+}
+
+unsafe impl Send for Basis {}
+unsafe impl Sync for Basis {}
+
+impl Default for Basis {
+    /// Returns the default basis, which is the standard basis.
+    fn default() -> Self {
+        Self {
+            x: (1.0, 0.0, 0.0).into(),
+            y: (0.0, 1.0, 0.0).into(),
+            z: (0.0, 0.0, 1.0).into(),
+        }
+    }
+}
+
+impl From<&Quaternion> for Basis {
+    /// Returns the basis rotated by the given rotation quaternion, which must
+    /// be normalized.
+    fn from(q: &Quaternion) -> Self {
+        let m = q.to_mat3_padded();
+
+        Self {
+            x: (m[0], m[1], m[2]).into(),
+            y: (m[4], m[5], m[6]).into(),
+            z: (m[8], m[9], m[10]).into(),
+        }
+    }
+}
+
+impl Basis {
+    /// Creates a new basis from the given axes if they are orthonormal and
+    /// right-handed within `epsilon`. Returns `None` otherwise.
+    pub fn try_new(x: Vector3, y: Vector3, z: Vector3, epsilon: f32) -> Option<Self> {
+        let basis = Self { x, y, z };
+
+        if basis.is_orthonormal(epsilon) && basis.handedness() == Handedness::Right {
+            Some(basis)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a right-handed orthonormal basis whose Z axis is the given
+    /// unit normal. The X and Y axes are chosen by
+    /// [`Vector3::any_orthonormal_pair`], so they flip discontinuously where
+    /// the Z component of `n` changes sign.
+    pub fn from_normal(n: &Vector3) -> Self {
+        let (x, y) = n.any_orthonormal_pair();
+
+        Self { x, y, z: *n }
+    }
+
+    /// Returns the rotation quaternion that takes the standard basis to this
+    /// basis, which must be orthonormal and right-handed.
+    pub fn to_quaternion(&self) -> Quaternion {
+        Quaternion::from_rotation_matrix(&self.to_matrix3())
+    }
+
+    /// Returns the matrix whose columns are the axes of this basis.
+    #[rustfmt::skip]
+    pub fn to_matrix3(&self) -> Matrix3 {
+        Matrix3::new(
+            self.x.x, self.y.x, self.z.x,
+            self.x.y, self.y.y, self.z.y,
+            self.x.z, self.y.z, self.z.z,
+        )
+    }
+
+    /// Returns the handedness of this basis.
+    pub fn handedness(&self) -> Handedness {
+        if self.x.cross(&self.y).dot(&self.z) >= 0.0 {
+            Handedness::Right
+        } else {
+            Handedness::Left
+        }
+    }
+
+    /// Returns whether the axes of this basis are unit vectors perpendicular
+    /// to each other within `epsilon`.
+    pub fn is_orthonormal(&self, epsilon: f32) -> bool {
+        [self.x, self.y, self.z]
+            .iter()
+            .all(|axis| (axis.dot(axis) - 1.0).abs() <= epsilon)
+            && self.x.dot(&self.y).abs() <= epsilon
+            && self.y.dot(&self.z).abs() <= epsilon
+            && self.z.dot(&self.x).abs() <= epsilon
+    }
+
+    /// Returns the spherical linear interpolation between the bases `a` and
+    /// `b` by the factor `t`, which rotates at a constant angular speed along
+    /// the shortest path. Both bases must be orthonormal and right-handed.
+    pub fn slerp(a: &Basis, b: &Basis, t: f32) -> Self {
+        Self::from(&Quaternion::slerp(
+            &a.to_quaternion(),
+            &b.to_quaternion(),
+            t,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    fn assert_vector3_eq(a: Vector3, b: Vector3) {
+        assert_float_absolute_eq!(a.x, b.x, 1e-5);
+        assert_float_absolute_eq!(a.y, b.y, 1e-5);
+        assert_float_absolute_eq!(a.z, b.z, 1e-5);
+    }
+
+    #[test]
+    fn test_from_normal() {
+        let normals: [Vector3; 4] = [
+            (0.0, 0.0, 1.0).into(),
+            (0.0, 0.0, -1.0).into(),
+            (0.6, 0.0, 0.8).into(),
+            (0.48, -0.6, -0.64).into(),
+        ];
+
+        for n in normals {
+            let basis = Basis::from_normal(&n);
+
+            assert_eq!(basis.z, n);
+            assert!(basis.is_orthonormal(1e-6));
+            assert_eq!(basis.handedness(), Handedness::Right);
+        }
+    }
+
+    #[test]
+    fn test_try_new() {
+        let x: Vector3 = (1.0, 0.0, 0.0).into();
+        let y: Vector3 = (0.0, 1.0, 0.0).into();
+        let z: Vector3 = (0.0, 0.0, 1.0).into();
+
+        assert_eq!(Basis::try_new(x, y, z, 1e-6), Some(Basis::default()));
+        assert_eq!(Basis::try_new(x, y, -z, 1e-6), None);
+        assert_eq!(Basis::try_new(x, y, z * 2.0, 1e-6), None);
+        assert_eq!(Basis::try_new(x, (0.6, 0.8, 0.0).into(), z, 1e-6), None);
+    }
+
+    #[test]
+    fn test_quaternion_roundtrip() {
+        let basis = Basis::from_normal(&(0.48, -0.6, -0.64).into());
+        let q = basis.to_quaternion();
+        let roundtrip = Basis::from(&q);
+
+        assert_vector3_eq(roundtrip.x, basis.x);
+        assert_vector3_eq(roundtrip.y, basis.y);
+        assert_vector3_eq(roundtrip.z, basis.z);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Basis::default();
+        let b = Basis::from(&Quaternion::from_rotation_z(1.0));
+
+        let start = Basis::slerp(&a, &b, 0.0);
+        let half = Basis::slerp(&a, &b, 0.5);
+        let expected = Basis::from(&Quaternion::from_rotation_z(0.5));
+
+        assert_vector3_eq(start.x, a.x);
+        assert_vector3_eq(half.x, expected.x);
+        assert_vector3_eq(half.y, expected.y);
+        assert_vector3_eq(half.z, expected.z);
+    }
+}
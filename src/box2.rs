@@ -0,0 +1,181 @@
+use std::{fmt, str::FromStr};
+
+use crate::format::{parse_rows, write_rows};
+
+use super::{ParseError, Vector2};
+
+/// 2D axis-aligned bounding box, e.g. a screen-space rectangle for scissor
+/// tests or UI hit regions.
+///
+/// A box is empty if its minimum is greater than its maximum on any axis.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Box2 {
+    /// The lower bound, i.e. the corner with the smallest coordinates.
+    pub min: Vector2,
+    /// The upper bound, i.e. the corner with the largest coordinates.
+    pub max: Vector2,
+}
+
+unsafe impl Send for Box2 {}
+unsafe impl Sync for Box2 {}
+
+impl Default for Box2 {
+    /// Returns the default box, which is empty.
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl fmt::Display for Box2 {
+    /// Formats this box as `((min.x, min.y), (max.x, max.y))`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, &[[self.min.x, self.min.y], [self.max.x, self.max.y]])
+    }
+}
+
+impl FromStr for Box2 {
+    type Err = ParseError;
+
+    /// Parses a box in the format written by `Display`, e.g.
+    /// `((min.x, min.y), (max.x, max.y))`. The outer parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [min, max] = parse_rows::<f32, 2, 2>(s)?;
+
+        Ok(Self {
+            min: Vector2 {
+                x: min[0],
+                y: min[1],
+            },
+            max: Vector2 {
+                x: max[0],
+                y: max[1],
+            },
+        })
+    }
+}
+
+impl Box2 {
+    /// Creates a new box with the given lower and upper bounds.
+    pub fn new(min: Vector2, max: Vector2) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns an empty box, which contains no points. Its union with any box
+    /// is the other box.
+    pub fn empty() -> Self {
+        Self {
+            min: (f32::INFINITY, f32::INFINITY).into(),
+            max: (f32::NEG_INFINITY, f32::NEG_INFINITY).into(),
+        }
+    }
+
+    /// Returns the smallest box that contains all of the given points, e.g.
+    /// the screen-space bounds of the projected corners of a 3D box. Returns
+    /// an empty box if there are no points.
+    pub fn from_points(points: &[Vector2]) -> Self {
+        points.iter().fold(Self::empty(), |b, p| Self {
+            min: (b.min.x.min(p.x), b.min.y.min(p.y)).into(),
+            max: (b.max.x.max(p.x), b.max.y.max(p.y)).into(),
+        })
+    }
+
+    /// Returns whether this box is empty.
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    /// Returns the smallest box that contains both this box and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: (self.min.x.min(other.min.x), self.min.y.min(other.min.y)).into(),
+            max: (self.max.x.max(other.max.x), self.max.y.max(other.max.y)).into(),
+        }
+    }
+
+    /// Returns the overlap of this box and `other`, which is empty if they do
+    /// not overlap.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            min: (self.min.x.max(other.min.x), self.min.y.max(other.min.y)).into(),
+            max: (self.max.x.min(other.max.x), self.max.y.min(other.max.y)).into(),
+        }
+    }
+
+    /// Returns whether the given point is inside this box or on its boundary.
+    pub fn contains_point(&self, point: &Vector2) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Returns whether `other` is entirely inside this box. An empty box is
+    /// inside every box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (other.min.x >= self.min.x
+                && other.max.x <= self.max.x
+                && other.min.y >= self.min.y
+                && other.max.y <= self.max.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert!(Box2::default().is_empty());
+        assert!(Box2::from_points(&[]).is_empty());
+        assert!(!Box2::new((0.0, 0.0).into(), (0.0, 0.0).into()).is_empty());
+    }
+
+    #[test]
+    fn test_from_points() {
+        let b = Box2::from_points(&[(1.0, 5.0).into(), (-2.0, 3.0).into(), (0.0, 7.0).into()]);
+
+        assert_eq!(b, Box2::new((-2.0, 3.0).into(), (1.0, 7.0).into()));
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let a = Box2::new((0.0, 0.0).into(), (2.0, 2.0).into());
+        let b = Box2::new((1.0, -1.0).into(), (3.0, 1.0).into());
+        let c = Box2::new((5.0, 5.0).into(), (6.0, 6.0).into());
+
+        assert_eq!(
+            a.union(&b),
+            Box2::new((0.0, -1.0).into(), (3.0, 2.0).into())
+        );
+        assert_eq!(a.union(&Box2::empty()), a);
+        assert_eq!(
+            a.intersection(&b),
+            Box2::new((1.0, 0.0).into(), (2.0, 1.0).into())
+        );
+        assert!(a.intersection(&c).is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let a = Box2::new((0.0, 0.0).into(), (2.0, 2.0).into());
+
+        assert!(a.contains_point(&(1.0, 2.0).into()));
+        assert!(!a.contains_point(&(1.0, 2.5).into()));
+        assert!(a.contains_box(&Box2::new((0.5, 0.5).into(), (1.0, 1.0).into())));
+        assert!(!a.contains_box(&Box2::new((0.5, 0.5).into(), (3.0, 1.0).into())));
+        assert!(a.contains_box(&Box2::empty()));
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let b = Box2::new((-1.0, 0.5).into(), (2.0, 3.0).into());
+
+        assert_eq!(b.to_string(), "((-1, 0.5), (2, 3))");
+        assert_eq!(b.to_string().parse(), Ok(b));
+        assert!("(-1, 0.5, 2, 3)".parse::<Box2>().is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use std::mem::size_of;
+
+use super::{
+    AxisAngle, Basis, Box2, Color, LinearRgba, Matrix3, Matrix4, NotEnoughElementsError,
+    Quaternion, Vector2, Vector3, Vector4,
+};
+
+/// Reading and writing values as bytes in an explicit byte order, e.g. for
+/// binary asset formats, regardless of the byte order of the target.
+///
+/// The components are read and written in memory order, i.e. in the same
+/// order as the fields and, for matrices, in column-major order.
+pub trait EndianBytes: Sized {
+    /// The size of the value in bytes.
+    const BYTE_SIZE: usize;
+
+    /// Reads a value from the start of the given little-endian bytes.
+    ///
+    /// Returns an error if there are fewer than [`Self::BYTE_SIZE`] bytes.
+    fn read_from_le_bytes(bytes: &[u8]) -> Result<Self, NotEnoughElementsError>;
+
+    /// Reads a value from the start of the given big-endian bytes.
+    ///
+    /// Returns an error if there are fewer than [`Self::BYTE_SIZE`] bytes.
+    fn read_from_be_bytes(bytes: &[u8]) -> Result<Self, NotEnoughElementsError>;
+
+    /// Writes this value to the start of the given bytes in little-endian
+    /// order.
+    ///
+    /// Returns an error if there are fewer than [`Self::BYTE_SIZE`] bytes.
+    fn write_to_le_bytes(&self, bytes: &mut [u8]) -> Result<(), NotEnoughElementsError>;
+
+    /// Writes this value to the start of the given bytes in big-endian order.
+    ///
+    /// Returns an error if there are fewer than [`Self::BYTE_SIZE`] bytes.
+    fn write_to_be_bytes(&self, bytes: &mut [u8]) -> Result<(), NotEnoughElementsError>;
+}
+
+/// Implements [`EndianBytes`] for a Pod type whose fields are all of the
+/// given float type.
+macro_rules! impl_endian_bytes {
+    ($t:ty, $f:ty) => {
+        impl EndianBytes for $t {
+            const BYTE_SIZE: usize = size_of::<$t>();
+
+            fn read_from_le_bytes(bytes: &[u8]) -> Result<Self, NotEnoughElementsError> {
+                read(bytes, <$f>::from_le_bytes)
+            }
+
+            fn read_from_be_bytes(bytes: &[u8]) -> Result<Self, NotEnoughElementsError> {
+                read(bytes, <$f>::from_be_bytes)
+            }
+
+            fn write_to_le_bytes(&self, bytes: &mut [u8]) -> Result<(), NotEnoughElementsError> {
+                write(self, bytes, <$f>::to_le_bytes)
+            }
+
+            fn write_to_be_bytes(&self, bytes: &mut [u8]) -> Result<(), NotEnoughElementsError> {
+                write(self, bytes, <$f>::to_be_bytes)
+            }
+        }
+    };
+}
+
+impl_endian_bytes!(Vector2, f32);
+impl_endian_bytes!(Vector3, f32);
+impl_endian_bytes!(Vector4, f32);
+impl_endian_bytes!(Quaternion, f32);
+impl_endian_bytes!(AxisAngle, f32);
+impl_endian_bytes!(Matrix3, f32);
+impl_endian_bytes!(Matrix4, f32);
+impl_endian_bytes!(Basis, f32);
+impl_endian_bytes!(Box2, f32);
+impl_endian_bytes!(Color, f64);
+impl_endian_bytes!(LinearRgba, f32);
+
+/// Returns an error unless `bytes` holds at least `expected` bytes.
+fn check_len(bytes: &[u8], expected: usize) -> Result<(), NotEnoughElementsError> {
+    if bytes.len() < expected {
+        Err(NotEnoughElementsError {
+            expected,
+            found: bytes.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Reads a value of type `T` made of floats of type `F`, decoding each float
+/// with `from_bytes`.
+fn read<T, F, const N: usize>(
+    bytes: &[u8],
+    from_bytes: fn([u8; N]) -> F,
+) -> Result<T, NotEnoughElementsError>
+where
+    T: bytemuck::Pod,
+    F: bytemuck::Pod,
+{
+    check_len(bytes, size_of::<T>())?;
+
+    let mut value = T::zeroed();
+    let floats: &mut [F] = bytemuck::cast_slice_mut(std::slice::from_mut(&mut value));
+
+    for (float, chunk) in floats.iter_mut().zip(bytes.chunks_exact(N)) {
+        *float = from_bytes(chunk.try_into().unwrap());
+    }
+
+    Ok(value)
+}
+
+/// Writes a value of type `T` made of floats of type `F`, encoding each float
+/// with `to_bytes`.
+fn write<T, F, const N: usize>(
+    value: &T,
+    bytes: &mut [u8],
+    to_bytes: fn(F) -> [u8; N],
+) -> Result<(), NotEnoughElementsError>
+where
+    T: bytemuck::Pod,
+    F: bytemuck::Pod,
+{
+    check_len(bytes, size_of::<T>())?;
+
+    let floats: &[F] = bytemuck::cast_slice(std::slice::from_ref(value));
+
+    for (float, chunk) in floats.iter().zip(bytes.chunks_exact_mut(N)) {
+        chunk.copy_from_slice(&to_bytes(*float));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector3() {
+        let v = Vector3 {
+            x: 1.0,
+            y: -2.0,
+            z: 0.5,
+        };
+        let mut le = [0u8; 12];
+        let mut be = [0u8; 12];
+
+        v.write_to_le_bytes(&mut le).unwrap();
+        v.write_to_be_bytes(&mut be).unwrap();
+
+        assert_eq!(le[..4], 1.0f32.to_le_bytes());
+        assert_eq!(be[4..8], (-2.0f32).to_be_bytes());
+        assert_eq!(Vector3::read_from_le_bytes(&le), Ok(v));
+        assert_eq!(Vector3::read_from_be_bytes(&be), Ok(v));
+    }
+
+    #[test]
+    fn test_color() {
+        let c = Color {
+            r: 0.25,
+            g: 0.5,
+            b: 1.0,
+        };
+        let mut bytes = [0u8; 32];
+
+        c.write_to_be_bytes(&mut bytes).unwrap();
+
+        assert_eq!(Color::BYTE_SIZE, 24);
+        assert_eq!(bytes[16..24], 1.0f64.to_be_bytes());
+        assert_eq!(Color::read_from_be_bytes(&bytes), Ok(c));
+    }
+
+    #[test]
+    fn test_not_enough_bytes() {
+        let error = NotEnoughElementsError {
+            expected: 64,
+            found: 63,
+        };
+
+        assert_eq!(Matrix4::read_from_le_bytes(&[0; 63]).unwrap_err(), error);
+        assert_eq!(
+            Matrix4::identity().write_to_le_bytes(&mut [0; 63]),
+            Err(error)
+        );
+    }
+}
@@ -1,3 +1,174 @@
+use std::{fmt, iter, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+
+use super::ParseError;
+
+/// Linear RGB color space, defined by its primaries and white point.
+///
+/// All supported color spaces use the D65 white point. The transfer functions
+/// (e.g. the sRGB curve) are not part of the color space here: colors are
+/// always linear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Linear sRGB, the default working color space.
+    LinearSrgb,
+    /// ITU-R BT.709, which shares its primaries with sRGB.
+    Rec709,
+    /// ITU-R BT.2020, the wide gamut used for UHD and HDR content.
+    Rec2020,
+    /// Display P3, the wide gamut of many modern displays and of `display-p3`
+    /// canvases on the web.
+    DisplayP3,
+}
+
+unsafe impl Send for ColorSpace {}
+unsafe impl Sync for ColorSpace {}
+
+impl Default for ColorSpace {
+    /// Returns the default color space, which is linear sRGB.
+    fn default() -> Self {
+        Self::LinearSrgb
+    }
+}
+
+impl ColorSpace {
+    /// Returns the matrix that converts linear RGB in this color space to CIE
+    /// XYZ, as rows.
+    ///
+    /// The values are derived from the primaries and the D65 white point. See
+    /// <http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html>.
+    #[rustfmt::skip]
+    fn to_xyz_matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::LinearSrgb | Self::Rec709 => [
+                [0.4123907992659595, 0.357584339383878, 0.1804807884018343],
+                [0.2126390058715104, 0.7151686787677559, 0.0721923153607337],
+                [0.0193308187155918, 0.1191947797946259, 0.9505321522496606],
+            ],
+            Self::Rec2020 => [
+                [0.6369580483012914, 0.1446169035862083, 0.1688809751391],
+                [0.2627002120112671, 0.6779980715188708, 0.0593017164698621],
+                [0.0, 0.0280726930490874, 1.0609850577107909],
+            ],
+            Self::DisplayP3 => [
+                [0.4865709486482162, 0.2656676931690931, 0.1982172852343625],
+                [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+                [0.0, 0.0451133818589026, 1.043944368900976],
+            ],
+        }
+    }
+}
+
+/// Reference white of an illuminant, used by chromatic adaptation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WhitePoint {
+    /// CIE standard illuminant D65 (average daylight), the white point of
+    /// sRGB and of every [`ColorSpace`].
+    D65,
+    /// CIE standard illuminant D50 (horizon light), the white point of ICC
+    /// profiles and of print.
+    D50,
+    /// A white point given by its CIE 1931 xy chromaticity coordinates, e.g.
+    /// one measured from a display.
+    Custom {
+        /// The x chromaticity coordinate.
+        x: f64,
+        /// The y chromaticity coordinate.
+        y: f64,
+    },
+}
+
+unsafe impl Send for WhitePoint {}
+unsafe impl Sync for WhitePoint {}
+
+impl Default for WhitePoint {
+    /// Returns the default white point, which is D65.
+    fn default() -> Self {
+        Self::D65
+    }
+}
+
+impl WhitePoint {
+    /// Returns the CIE 1931 xy chromaticity coordinates of this white point.
+    pub fn chromaticity(self) -> (f64, f64) {
+        match self {
+            Self::D65 => (0.3127, 0.329),
+            Self::D50 => (0.3457, 0.3585),
+            Self::Custom { x, y } => (x, y),
+        }
+    }
+
+    /// Returns the CIE XYZ coordinates of this white point, normalized to a
+    /// luminance `Y` of `1.0`.
+    fn to_xyz(self) -> [f64; 3] {
+        let (x, y) = self.chromaticity();
+
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+}
+
+/// Method of chromatic adaptation, i.e. of predicting how a color seen under
+/// one illuminant appears under another.
+///
+/// Both methods scale the responses of a cone-like space by the ratio of the
+/// white points, and differ in the cone-like space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, the most accurate of the two and the one used
+    /// by ICC profiles.
+    Bradford,
+    /// The von Kries transform in the Hunt-Pointer-Estevez cone space.
+    VonKries,
+}
+
+unsafe impl Send for ChromaticAdaptation {}
+unsafe impl Sync for ChromaticAdaptation {}
+
+impl Default for ChromaticAdaptation {
+    /// Returns the default method, which is Bradford.
+    fn default() -> Self {
+        Self::Bradford
+    }
+}
+
+impl ChromaticAdaptation {
+    /// Returns the given CIE XYZ color, seen under the illuminant with the
+    /// white point `from`, adapted to the white point `to` with this method.
+    fn adapt(self, xyz: [f64; 3], from: WhitePoint, to: WhitePoint) -> [f64; 3] {
+        if from == to {
+            return xyz;
+        }
+
+        let cone_matrix = self.cone_matrix();
+        let from_cone = mul_matrix_vector(&cone_matrix, from.to_xyz());
+        let to_cone = mul_matrix_vector(&cone_matrix, to.to_xyz());
+
+        let cone = mul_matrix_vector(&cone_matrix, xyz);
+        let adapted = [0, 1, 2].map(|i| cone[i] * to_cone[i] / from_cone[i]);
+
+        mul_matrix_vector(&invert_matrix(&cone_matrix), adapted)
+    }
+
+    /// Returns the matrix that converts CIE XYZ to the cone-like space of this
+    /// method, as rows.
+    #[rustfmt::skip]
+    fn cone_matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Self::VonKries => [
+                [0.40024, 0.7076, -0.08081],
+                [-0.2263, 1.16532, 0.0457],
+                [0.0, 0.0, 0.91822],
+            ],
+        }
+    }
+}
+
 /// RGB color in the working color space.
 ///
 /// All channel values are normalized and thus are free from color depth limits.
@@ -28,6 +199,43 @@ impl Default for Color {
 
 impl Eq for Color {}
 
+impl fmt::Display for Color {
+    /// Formats this color as `(r, g, b)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.r, self.g, self.b])
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseError;
+
+    /// Parses a color in the format written by `Display`, e.g. `(r, g, b)`. The
+    /// parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [r, g, b] = parse_components::<f64, 3>(s)?;
+
+        Ok(Self { r, g, b })
+    }
+}
+
+impl iter::Sum for Color {
+    fn sum<I: Iterator<Item = Color>>(iter: I) -> Self {
+        iter.fold(Color::default(), |acc, c| Color {
+            r: acc.r + c.r,
+            g: acc.g + c.g,
+            b: acc.b + c.b,
+        })
+    }
+}
+
+impl<'a> iter::Sum<&'a Color> for Color {
+    fn sum<I: Iterator<Item = &'a Color>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl Color {
     /// Sets the RGB components of this color.
     pub fn set(&mut self, r: f64, g: f64, b: f64) {
@@ -35,4 +243,966 @@ impl Color {
         self.g = g;
         self.b = b;
     }
+
+    /// Parses a color from a hex string of the form `#RRGGBB` or `#RRGGBBAA`,
+    /// as used by CSS and design tools. The `#` is optional and the digits
+    /// are case-insensitive. Alpha, if any, is ignored; use
+    /// [`LinearRgba::from_hex`] to keep it.
+    ///
+    /// Each channel is mapped from `0..=255` to `0.0..=1.0` as is, without
+    /// applying a transfer function. Hex colors are usually sRGB-encoded, so
+    /// use [`Color::to_linear`] to decode them.
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        let ([r, g, b], _) = parse_hex(s)?;
+
+        Ok(Self {
+            r: r as f64 / 255.0,
+            g: g as f64 / 255.0,
+            b: b as f64 / 255.0,
+        })
+    }
+
+    /// Returns this color as a lowercase hex string of the form `#rrggbb`.
+    /// Channels are clamped between `0.0` and `1.0` and rounded to the nearest
+    /// of the 256 levels.
+    pub fn to_hex(&self) -> String {
+        let [r, g, b] = [self.r, self.g, self.b].map(to_byte);
+
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+
+    /// Returns this color, whose channels are encoded with the sRGB transfer
+    /// function (e.g. a color picked in an image editor), decoded to linear
+    /// values, e.g. before writing it to a linear `rgba16float` texture.
+    ///
+    /// This uses the exact piecewise sRGB curve rather than a gamma of 2.2,
+    /// which matters for dark tones. Negative channels are decoded as the
+    /// negation of their absolute value, as in extended sRGB.
+    pub fn to_linear(&self) -> Self {
+        Self {
+            r: srgb_to_linear(self.r),
+            g: srgb_to_linear(self.g),
+            b: srgb_to_linear(self.b),
+        }
+    }
+
+    /// Returns this color, whose channels are linear, encoded with the sRGB
+    /// transfer function. This is the inverse of [`Color::to_linear`].
+    pub fn to_srgb(&self) -> Self {
+        Self {
+            r: linear_to_srgb(self.r),
+            g: linear_to_srgb(self.g),
+            b: linear_to_srgb(self.b),
+        }
+    }
+
+    /// Returns the [OKLab][oklab] coordinates `(l, a, b)` of this color,
+    /// which is in linear sRGB.
+    ///
+    /// OKLab is perceptually uniform: `l` is the perceived lightness, and `a`
+    /// and `b` are how green-red and blue-yellow the color is. Distances and
+    /// interpolation in OKLab match how colors are perceived much better than
+    /// in RGB.
+    ///
+    /// [oklab]: https://bottosson.github.io/posts/oklab/
+    #[rustfmt::skip]
+    pub fn to_oklab(&self) -> (f64, f64, f64) {
+        let lms = mul_matrix_vector(
+            &[
+                [0.4122214708, 0.5363325363, 0.0514459929],
+                [0.2119034982, 0.6806995451, 0.1073969566],
+                [0.0883024619, 0.2817188376, 0.6299787005],
+            ],
+            [self.r, self.g, self.b],
+        );
+        let [l, a, b] = mul_matrix_vector(
+            &[
+                [0.2104542553, 0.793617785, -0.0040720468],
+                [1.9779984951, -2.428592205, 0.4505937099],
+                [0.0259040371, 0.7827717662, -0.808675766],
+            ],
+            lms.map(f64::cbrt),
+        );
+
+        (l, a, b)
+    }
+
+    /// Creates a color in linear sRGB from the given OKLab coordinates. See
+    /// [`Color::to_oklab`].
+    #[rustfmt::skip]
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Self {
+        let lms = mul_matrix_vector(
+            &[
+                [1.0, 0.3963377774, 0.2158037573],
+                [1.0, -0.1055613458, -0.0638541728],
+                [1.0, -0.0894841775, -1.291485548],
+            ],
+            [l, a, b],
+        );
+        let [r, g, b] = mul_matrix_vector(
+            &[
+                [4.0767416621, -3.3077115913, 0.2309699292],
+                [-1.2684380046, 2.6097574011, -0.3413193965],
+                [-0.0041960863, -0.7034186147, 1.707614701],
+            ],
+            lms.map(|x| x * x * x),
+        );
+
+        Self { r, g, b }
+    }
+
+    /// Returns the OKLCH coordinates `(l, c, h)` of this color, which is in
+    /// linear sRGB. These are the OKLab coordinates in polar form: `c` is the
+    /// chroma and `h` is the hue angle in radians between `-π` and `π`.
+    pub fn to_oklch(&self) -> (f64, f64, f64) {
+        let (l, a, b) = self.to_oklab();
+
+        (l, a.hypot(b), b.atan2(a))
+    }
+
+    /// Creates a color in linear sRGB from the given OKLCH coordinates, with
+    /// the hue in radians. See [`Color::to_oklch`].
+    pub fn from_oklch(l: f64, c: f64, h: f64) -> Self {
+        let (sin, cos) = h.sin_cos();
+
+        Self::from_oklab(l, c * cos, c * sin)
+    }
+
+    /// Returns the linear interpolation between this color and `other` by the
+    /// factor `t`, where `t = 0.0` returns this color and `t = 1.0` returns
+    /// `other`. `t` is not clamped.
+    ///
+    /// The channels are interpolated as is, so interpolating linear colors
+    /// is physically correct, e.g. for blending light, but may look uneven.
+    /// See [`Color::lerp_oklab`] for perceptually even interpolation.
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        Self {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    /// Returns the interpolation between this color and `other` by the factor
+    /// `t` in OKLab, where `t = 0.0` returns this color and `t = 1.0` returns
+    /// `other`. Both colors are in linear sRGB.
+    ///
+    /// Unlike interpolating the RGB channels, this keeps the perceived
+    /// lightness and saturation even along the way, e.g. for gradients.
+    pub fn lerp_oklab(&self, other: &Self, t: f64) -> Self {
+        let (l1, a1, b1) = self.to_oklab();
+        let (l2, a2, b2) = other.to_oklab();
+
+        Self::from_oklab(l1 + (l2 - l1) * t, a1 + (a2 - a1) * t, b1 + (b2 - b1) * t)
+    }
+
+    /// Returns this color, which is in the color space `from`, converted to the
+    /// color space `to`.
+    ///
+    /// Colors outside the gamut of `to` are not clipped, so some channels may
+    /// be negative or greater than `1.0`.
+    pub fn convert(&self, from: ColorSpace, to: ColorSpace) -> Self {
+        if from.to_xyz_matrix() == to.to_xyz_matrix() {
+            return *self;
+        }
+
+        let xyz = mul_matrix_vector(&from.to_xyz_matrix(), [self.r, self.g, self.b]);
+        let [r, g, b] = mul_matrix_vector(&invert_matrix(&to.to_xyz_matrix()), xyz);
+
+        Self { r, g, b }
+    }
+
+    /// Returns this color, which is in the color space `space` and was seen
+    /// under the illuminant with the white point `from`, as it would appear
+    /// under the illuminant with the white point `to`, e.g. to white-balance
+    /// a photo.
+    ///
+    /// Unlike scaling the RGB channels, this adapts the color in a cone-like
+    /// space, so it is colorimetrically correct.
+    pub fn adapt_white_point(
+        &self,
+        space: ColorSpace,
+        from: WhitePoint,
+        to: WhitePoint,
+        method: ChromaticAdaptation,
+    ) -> Self {
+        if from == to {
+            return *self;
+        }
+
+        let xyz = mul_matrix_vector(&space.to_xyz_matrix(), [self.r, self.g, self.b]);
+        let xyz = method.adapt(xyz, from, to);
+        let [r, g, b] = mul_matrix_vector(&invert_matrix(&space.to_xyz_matrix()), xyz);
+
+        Self { r, g, b }
+    }
+
+    /// Returns the CIE XYZ coordinates `(x, y, z)` of this color, which is in
+    /// the color space `space`, relative to the given white point, e.g. D50
+    /// for ICC profiles. White has a luminance `y` of `1.0`.
+    ///
+    /// The color spaces use the D65 white point, so for other white points
+    /// the coordinates are adapted with the Bradford transform.
+    pub fn to_xyz(&self, space: ColorSpace, white_point: WhitePoint) -> (f64, f64, f64) {
+        let xyz = mul_matrix_vector(&space.to_xyz_matrix(), [self.r, self.g, self.b]);
+        let [x, y, z] = ChromaticAdaptation::Bradford.adapt(xyz, WhitePoint::D65, white_point);
+
+        (x, y, z)
+    }
+
+    /// Creates a color in the color space `space` from the given CIE XYZ
+    /// coordinates relative to the given white point. This is the inverse of
+    /// [`Color::to_xyz`].
+    pub fn from_xyz(x: f64, y: f64, z: f64, space: ColorSpace, white_point: WhitePoint) -> Self {
+        let xyz = ChromaticAdaptation::Bradford.adapt([x, y, z], white_point, WhitePoint::D65);
+        let [r, g, b] = mul_matrix_vector(&invert_matrix(&space.to_xyz_matrix()), xyz);
+
+        Self { r, g, b }
+    }
+
+    /// Returns the CIE xyY coordinates `(x, y, luminance)` of this color,
+    /// which is in the color space `space`, relative to the given white point.
+    /// `x` and `y` are the chromaticity, as used by chromaticity diagrams and
+    /// to specify display primaries.
+    ///
+    /// Black has no chromaticity, so it gets the chromaticity of the white
+    /// point.
+    pub fn to_xyy(&self, space: ColorSpace, white_point: WhitePoint) -> (f64, f64, f64) {
+        let (x, y, z) = self.to_xyz(space, white_point);
+        let sum = x + y + z;
+
+        if sum == 0.0 {
+            let (white_x, white_y) = white_point.chromaticity();
+
+            return (white_x, white_y, 0.0);
+        }
+
+        (x / sum, y / sum, y)
+    }
+
+    /// Creates a color in the color space `space` from the given CIE xyY
+    /// coordinates relative to the given white point. This is the inverse of
+    /// [`Color::to_xyy`]. A chromaticity `y` of `0.0` gives black.
+    pub fn from_xyy(
+        x: f64,
+        y: f64,
+        luminance: f64,
+        space: ColorSpace,
+        white_point: WhitePoint,
+    ) -> Self {
+        if y == 0.0 {
+            return Self::default();
+        }
+
+        let scale = luminance / y;
+
+        Self::from_xyz(
+            x * scale,
+            luminance,
+            (1.0 - x - y) * scale,
+            space,
+            white_point,
+        )
+    }
+
+    /// Merges several samples of the same scene point, each captured with a
+    /// different exposure, into a single high dynamic range color.
+    ///
+    /// Each sample is a color and the exposure it was captured with (e.g. the
+    /// exposure time). Samples are divided by their exposure and averaged
+    /// per channel, weighted by a hat function that favors well-exposed
+    /// values over ones close to `0.0` or `1.0`. If every sample of a channel
+    /// is fully under- or overexposed, the unweighted average is used
+    /// instead.
+    ///
+    /// Samples whose exposure is not positive are ignored, since they cannot
+    /// be scaled back to scene radiance. Returns black if there are no other
+    /// samples.
+    pub fn merge_exposures(samples: &[(Color, f64)]) -> Self {
+        let merge_channel = |channel: fn(&Color) -> f64| {
+            let mut weighted_sum = 0.0;
+            let mut weight_sum = 0.0;
+            let mut sum = 0.0;
+            let mut count = 0;
+
+            for (color, exposure) in samples.iter().filter(|(_, exposure)| *exposure > 0.0) {
+                let value = channel(color);
+                let weight = (1.0 - (2.0 * value - 1.0).abs()).max(0.0);
+
+                weighted_sum += weight * value / exposure;
+                weight_sum += weight;
+                sum += value / exposure;
+                count += 1;
+            }
+
+            if weight_sum > 0.0 {
+                weighted_sum / weight_sum
+            } else if count == 0 {
+                0.0
+            } else {
+                sum / count as f64
+            }
+        };
+
+        Self {
+            r: merge_channel(|c| c.r),
+            g: merge_channel(|c| c.g),
+            b: merge_channel(|c| c.b),
+        }
+    }
+
+    /// Returns the per-channel logarithmic (geometric) average of the given
+    /// colors, e.g. the log-average luminance used for exposure and tone
+    /// mapping.
+    ///
+    /// A small offset is added to each channel before taking the logarithm so
+    /// that black samples do not collapse the average to zero.
+    ///
+    /// Returns black if there are no colors.
+    pub fn log_average(colors: &[Color]) -> Self {
+        const DELTA: f64 = 1e-6;
+
+        if colors.is_empty() {
+            return Self::default();
+        }
+
+        let n = colors.len() as f64;
+        let average = |channel: fn(&Color) -> f64| {
+            let log_sum: f64 = colors.iter().map(|c| (DELTA + channel(c)).ln()).sum();
+
+            (log_sum / n).exp() - DELTA
+        };
+
+        Self {
+            r: average(|c| c.r),
+            g: average(|c| c.g),
+            b: average(|c| c.b),
+        }
+    }
+}
+
+/// Linear RGBA color with `f32` channels, for writing colors to GPU buffers.
+///
+/// Unlike [`Color`], whose channels are `f64`, this matches the memory layout
+/// of `vec4<f32>` in WGSL. You can convert a [`Color`] to a `LinearRgba` using
+/// `.into()`, which makes it opaque.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LinearRgba {
+    /// Red channel value between `0.0` and `1.0`.
+    pub r: f32,
+    /// Green channel value between `0.0` and `1.0`.
+    pub g: f32,
+    /// Blue channel value between `0.0` and `1.0`.
+    pub b: f32,
+    /// Alpha (opacity) value between `0.0` and `1.0`.
+    pub a: f32,
+}
+
+unsafe impl Send for LinearRgba {}
+unsafe impl Sync for LinearRgba {}
+
+impl Default for LinearRgba {
+    /// Returns the default color, which is opaque black.
+    fn default() -> Self {
+        Self {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        }
+    }
+}
+
+impl Eq for LinearRgba {}
+
+impl fmt::Display for LinearRgba {
+    /// Formats this color as `(r, g, b, a)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.r, self.g, self.b, self.a])
+    }
+}
+
+impl FromStr for LinearRgba {
+    type Err = ParseError;
+
+    /// Parses a color in the format written by `Display`, e.g. `(r, g, b, a)`.
+    /// The parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [r, g, b, a] = parse_components::<f32, 4>(s)?;
+
+        Ok(Self { r, g, b, a })
+    }
+}
+
+impl From<Color> for LinearRgba {
+    /// Converts the given color to an opaque `LinearRgba`, rounding each
+    /// channel to the nearest `f32`.
+    fn from(color: Color) -> Self {
+        Self::from_color(&color, 1.0)
+    }
+}
+
+impl From<LinearRgba> for Color {
+    /// Converts the given color to a [`Color`], dropping alpha. The channels
+    /// are converted exactly.
+    fn from(color: LinearRgba) -> Self {
+        Self {
+            r: color.r as f64,
+            g: color.g as f64,
+            b: color.b as f64,
+        }
+    }
+}
+
+impl LinearRgba {
+    /// Creates a new color with the given channels.
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new color from the given [`Color`] and alpha, rounding each
+    /// channel to the nearest `f32`.
+    pub fn from_color(color: &Color, a: f32) -> Self {
+        Self {
+            r: color.r as f32,
+            g: color.g as f32,
+            b: color.b as f32,
+            a,
+        }
+    }
+
+    /// Parses a color from a hex string of the form `#RRGGBB` or `#RRGGBBAA`,
+    /// like [`Color::from_hex`]. Colors without alpha are opaque.
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        let ([r, g, b], a) = parse_hex(s)?;
+
+        Ok(Self {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+            a: a.map_or(1.0, |a| a as f32 / 255.0),
+        })
+    }
+
+    /// Returns this color as a lowercase hex string of the form `#rrggbbaa`,
+    /// like [`Color::to_hex`].
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_wgsl_vec4().map(|c| to_byte(c as f64));
+
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+
+    /// Returns the channels of this color in RGBA order, ready to be written
+    /// to a `vec4<f32>` in a WGSL uniform or storage buffer.
+    pub fn to_wgsl_vec4(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+/// Parses the channels of a hex color of the form `#RRGGBB` or `#RRGGBBAA`,
+/// where the `#` is optional.
+fn parse_hex(s: &str) -> Result<([u8; 3], Option<u8>), ParseError> {
+    let digits = s.strip_prefix('#').unwrap_or(s);
+
+    if !(digits.len() == 6 || digits.len() == 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseError::InvalidHex);
+    }
+
+    // The digits are ASCII, so every pair is on character boundaries.
+    let byte = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
+    let alpha = (digits.len() == 8).then(|| byte(3));
+
+    Ok(([byte(0), byte(1), byte(2)], alpha))
+}
+
+/// Converts the given channel value to a byte, clamping it between `0.0` and
+/// `1.0`.
+fn to_byte(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Decodes the given sRGB-encoded channel value to a linear value.
+fn srgb_to_linear(value: f64) -> f64 {
+    let abs = value.abs();
+    let linear = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+
+    linear.copysign(value)
+}
+
+/// Encodes the given linear channel value with the sRGB transfer function.
+fn linear_to_srgb(value: f64) -> f64 {
+    let abs = value.abs();
+    let encoded = if abs <= 0.0031308 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+
+    encoded.copysign(value)
+}
+
+/// Multiplies the given 3x3 matrix, as rows, by the given column vector.
+fn mul_matrix_vector(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// Returns the inverse of the given invertible 3x3 matrix, as rows.
+fn invert_matrix(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let [[a, b, c], [d, e, f], [g, h, i]] = *m;
+
+    let det = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+
+    [
+        [e * i - f * h, c * h - b * i, b * f - c * e],
+        [f * g - d * i, a * i - c * g, c * d - a * f],
+        [d * h - e * g, b * g - a * h, a * e - b * d],
+    ]
+    .map(|row| row.map(|x| x / det))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_display_and_from_str() {
+        let c = Color {
+            r: 0.1,
+            g: 0.5,
+            b: 1.0,
+        };
+
+        assert_eq!(c.to_string(), "(0.1, 0.5, 1)");
+        assert_eq!(c.to_string().parse(), Ok(c));
+        assert!("(0.1, 0.5, blue)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_sum() {
+        let colors = [
+            Color {
+                r: 0.25,
+                g: 0.5,
+                b: 0.0,
+            },
+            Color {
+                r: 0.5,
+                g: 0.25,
+                b: 1.0,
+            },
+        ];
+        let expected = Color {
+            r: 0.75,
+            g: 0.75,
+            b: 1.0,
+        };
+
+        assert_eq!(colors.iter().sum::<Color>(), expected);
+        assert_eq!(colors.into_iter().sum::<Color>(), expected);
+    }
+
+    #[test]
+    fn test_merge_exposures() {
+        let samples = [
+            (
+                Color {
+                    r: 0.25,
+                    g: 0.5,
+                    b: 0.0,
+                },
+                1.0,
+            ),
+            (
+                Color {
+                    r: 0.5,
+                    g: 1.0,
+                    b: 0.0,
+                },
+                2.0,
+            ),
+        ];
+
+        let merged = Color::merge_exposures(&samples);
+
+        assert_float_absolute_eq!(merged.r, 0.25);
+        // The overexposed sample has no weight.
+        assert_float_absolute_eq!(merged.g, 0.5);
+        // Every sample is underexposed, so the plain average is used.
+        assert_float_absolute_eq!(merged.b, 0.0);
+
+        assert_eq!(Color::merge_exposures(&[]), Color::default());
+    }
+
+    #[test]
+    fn test_merge_exposures_non_positive() {
+        let c = Color {
+            r: 0.25,
+            g: 0.5,
+            b: 0.75,
+        };
+
+        let merged = Color::merge_exposures(&[(c, 0.0), (c, -1.0), (c, 0.5)]);
+
+        assert_float_absolute_eq!(merged.r, 0.5);
+        assert_float_absolute_eq!(merged.g, 1.0);
+        assert_float_absolute_eq!(merged.b, 1.5);
+
+        assert_eq!(Color::merge_exposures(&[(c, 0.0)]), Color::default());
+    }
+
+    #[test]
+    fn test_log_average() {
+        let colors = [
+            Color {
+                r: 1.0,
+                g: 2.0,
+                b: 0.5,
+            },
+            Color {
+                r: 4.0,
+                g: 2.0,
+                b: 0.5,
+            },
+        ];
+
+        let average = Color::log_average(&colors);
+
+        assert_float_absolute_eq!(average.r, 2.0, 1e-5);
+        assert_float_absolute_eq!(average.g, 2.0, 1e-5);
+        assert_float_absolute_eq!(average.b, 0.5, 1e-5);
+
+        assert_eq!(Color::log_average(&[]), Color::default());
+    }
+
+    #[test]
+    fn test_convert() {
+        let color = Color {
+            r: 0.2,
+            g: 0.5,
+            b: 0.8,
+        };
+
+        assert_eq!(
+            color.convert(ColorSpace::LinearSrgb, ColorSpace::Rec709),
+            color
+        );
+
+        // White stays white since every color space uses D65.
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+        let p3_white = white.convert(ColorSpace::LinearSrgb, ColorSpace::DisplayP3);
+
+        assert_float_absolute_eq!(p3_white.r, 1.0, 1e-6);
+        assert_float_absolute_eq!(p3_white.g, 1.0, 1e-6);
+        assert_float_absolute_eq!(p3_white.b, 1.0, 1e-6);
+
+        // Pure sRGB red is inside the Rec. 2020 gamut.
+        let red = Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+        };
+        let rec2020_red = red.convert(ColorSpace::LinearSrgb, ColorSpace::Rec2020);
+
+        assert_float_absolute_eq!(rec2020_red.r, 0.6274, 1e-4);
+        assert_float_absolute_eq!(rec2020_red.g, 0.0691, 1e-4);
+        assert_float_absolute_eq!(rec2020_red.b, 0.0164, 1e-4);
+
+        let roundtrip = color
+            .convert(ColorSpace::LinearSrgb, ColorSpace::Rec2020)
+            .convert(ColorSpace::Rec2020, ColorSpace::DisplayP3)
+            .convert(ColorSpace::DisplayP3, ColorSpace::LinearSrgb);
+
+        assert_float_absolute_eq!(roundtrip.r, color.r, 1e-9);
+        assert_float_absolute_eq!(roundtrip.g, color.g, 1e-9);
+        assert_float_absolute_eq!(roundtrip.b, color.b, 1e-9);
+    }
+
+    #[test]
+    fn test_linear_rgba() {
+        let color = Color {
+            r: 0.25,
+            g: 0.5,
+            b: 1.0,
+        };
+        let rgba = LinearRgba::from(color);
+
+        assert_eq!(rgba, LinearRgba::new(0.25, 0.5, 1.0, 1.0));
+        assert_eq!(Color::from(rgba), color);
+        assert_eq!(
+            LinearRgba::from_color(&color, 0.5).to_wgsl_vec4(),
+            [0.25, 0.5, 1.0, 0.5]
+        );
+        assert_eq!(bytemuck::bytes_of(&rgba).len(), 16);
+        assert_eq!(LinearRgba::default().a, 1.0);
+    }
+
+    #[test]
+    fn test_adapt_white_point() {
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+        let color = Color {
+            r: 0.2,
+            g: 0.6,
+            b: 0.4,
+        };
+
+        for method in [ChromaticAdaptation::Bradford, ChromaticAdaptation::VonKries] {
+            let space = ColorSpace::LinearSrgb;
+
+            // White under D65 maps to the white point of D50, which is warmer.
+            let adapted = white.adapt_white_point(space, WhitePoint::D65, WhitePoint::D50, method);
+            let xyz = mul_matrix_vector(&space.to_xyz_matrix(), [adapted.r, adapted.g, adapted.b]);
+
+            assert!(adapted.r > adapted.g && adapted.g > adapted.b);
+            assert_float_absolute_eq!(xyz[0] / xyz[1], 0.3457 / 0.3585, 1e-6);
+            assert_float_absolute_eq!(xyz[2] / xyz[1], (1.0 - 0.3457 - 0.3585) / 0.3585, 1e-6);
+
+            // Adapting back restores the color.
+            let custom = WhitePoint::Custom { x: 0.3, y: 0.31 };
+            let roundtrip = color
+                .adapt_white_point(space, WhitePoint::D65, custom, method)
+                .adapt_white_point(space, custom, WhitePoint::D65, method);
+
+            assert_float_absolute_eq!(roundtrip.r, color.r, 1e-9);
+            assert_float_absolute_eq!(roundtrip.g, color.g, 1e-9);
+            assert_float_absolute_eq!(roundtrip.b, color.b, 1e-9);
+        }
+
+        let same = color.adapt_white_point(
+            ColorSpace::Rec2020,
+            WhitePoint::D50,
+            WhitePoint::D50,
+            ChromaticAdaptation::Bradford,
+        );
+
+        assert_eq!(same, color);
+    }
+
+    #[test]
+    fn test_hex() {
+        let color = Color::from_hex("#FF8000").unwrap();
+
+        assert_eq!(color.r, 1.0);
+        assert_float_absolute_eq!(color.g, 128.0 / 255.0);
+        assert_eq!(color.b, 0.0);
+        assert_eq!(color.to_hex(), "#ff8000");
+        assert_eq!(Color::from_hex("ff800080"), Ok(color));
+
+        let rgba = LinearRgba::from_hex("#ff800080").unwrap();
+
+        assert_float_absolute_eq!(rgba.a, 128.0 / 255.0);
+        assert_eq!(rgba.to_hex(), "#ff800080");
+        assert_eq!(LinearRgba::from_hex("#ff8000").unwrap().a, 1.0);
+
+        let out_of_range = Color {
+            r: 2.0,
+            g: -1.0,
+            b: 0.5,
+        };
+
+        assert_eq!(out_of_range.to_hex(), "#ff0080");
+
+        for invalid in [
+            "",
+            "#",
+            "#ff80",
+            "#ff80000",
+            "#gg8000",
+            "#ff8000ff00",
+            "#ff80é",
+        ] {
+            assert_eq!(Color::from_hex(invalid), Err(ParseError::InvalidHex));
+        }
+    }
+
+    #[test]
+    fn test_srgb_transfer() {
+        let srgb = Color {
+            r: 0.5,
+            g: 0.02,
+            b: 1.0,
+        };
+        let linear = srgb.to_linear();
+
+        assert_float_absolute_eq!(linear.r, 0.214041140, 1e-9);
+        // Dark tones are on the linear segment of the curve.
+        assert_float_absolute_eq!(linear.g, 0.02 / 12.92, 1e-12);
+        assert_float_absolute_eq!(linear.b, 1.0, 1e-12);
+
+        let roundtrip = linear.to_srgb();
+
+        assert_float_absolute_eq!(roundtrip.r, srgb.r, 1e-12);
+        assert_float_absolute_eq!(roundtrip.g, srgb.g, 1e-12);
+        assert_float_absolute_eq!(roundtrip.b, srgb.b, 1e-12);
+
+        let negative = Color {
+            r: -0.5,
+            g: 0.0,
+            b: 0.0,
+        };
+
+        assert_float_absolute_eq!(negative.to_linear().r, -0.214041140, 1e-9);
+    }
+
+    #[test]
+    fn test_oklab() {
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+        let (l, a, b) = white.to_oklab();
+
+        assert_float_absolute_eq!(l, 1.0, 1e-6);
+        assert_float_absolute_eq!(a, 0.0, 1e-6);
+        assert_float_absolute_eq!(b, 0.0, 1e-6);
+
+        // Reference values from https://bottosson.github.io/posts/oklab/.
+        let red = Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+        };
+        let (l, a, b) = red.to_oklab();
+
+        assert_float_absolute_eq!(l, 0.627955, 1e-5);
+        assert_float_absolute_eq!(a, 0.224863, 1e-5);
+        assert_float_absolute_eq!(b, 0.125846, 1e-5);
+
+        let color = Color {
+            r: 0.2,
+            g: 0.6,
+            b: 0.4,
+        };
+        let (l, a, b) = color.to_oklab();
+        let roundtrip = Color::from_oklab(l, a, b);
+
+        assert_float_absolute_eq!(roundtrip.r, color.r, 1e-6);
+        assert_float_absolute_eq!(roundtrip.g, color.g, 1e-6);
+        assert_float_absolute_eq!(roundtrip.b, color.b, 1e-6);
+
+        let (l, c, h) = color.to_oklch();
+        let roundtrip = Color::from_oklch(l, c, h);
+
+        assert_float_absolute_eq!(roundtrip.r, color.r, 1e-6);
+        assert_float_absolute_eq!(roundtrip.g, color.g, 1e-6);
+        assert_float_absolute_eq!(roundtrip.b, color.b, 1e-6);
+    }
+
+    #[test]
+    fn test_lerp_oklab() {
+        let black = Color::default();
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+
+        let start = black.lerp_oklab(&white, 0.0);
+        let end = black.lerp_oklab(&white, 1.0);
+
+        assert_float_absolute_eq!(start.r, 0.0, 1e-9);
+        assert_float_absolute_eq!(end.g, 1.0, 1e-6);
+
+        // The perceptual midpoint of black and white is darker than the
+        // linear midpoint.
+        let middle = black.lerp_oklab(&white, 0.5);
+
+        assert_float_absolute_eq!(middle.to_oklab().0, 0.5, 1e-6);
+        assert!(middle.r < 0.5);
+    }
+
+    #[test]
+    fn test_xyz_and_xyy() {
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+
+        for white_point in [WhitePoint::D65, WhitePoint::D50] {
+            let (x, y, luminance) = white.to_xyy(ColorSpace::LinearSrgb, white_point);
+            let (white_x, white_y) = white_point.chromaticity();
+
+            assert_float_absolute_eq!(x, white_x, 1e-4);
+            assert_float_absolute_eq!(y, white_y, 1e-4);
+            assert_float_absolute_eq!(luminance, 1.0, 1e-6);
+        }
+
+        let color = Color {
+            r: 0.2,
+            g: 0.6,
+            b: 0.4,
+        };
+
+        for space in [ColorSpace::LinearSrgb, ColorSpace::DisplayP3] {
+            let (x, y, z) = color.to_xyz(space, WhitePoint::D50);
+            let roundtrip = Color::from_xyz(x, y, z, space, WhitePoint::D50);
+
+            assert_float_absolute_eq!(roundtrip.r, color.r, 1e-9);
+            assert_float_absolute_eq!(roundtrip.g, color.g, 1e-9);
+            assert_float_absolute_eq!(roundtrip.b, color.b, 1e-9);
+
+            let (x, y, luminance) = color.to_xyy(space, WhitePoint::D65);
+            let roundtrip = Color::from_xyy(x, y, luminance, space, WhitePoint::D65);
+
+            assert_float_absolute_eq!(roundtrip.r, color.r, 1e-9);
+            assert_float_absolute_eq!(roundtrip.g, color.g, 1e-9);
+            assert_float_absolute_eq!(roundtrip.b, color.b, 1e-9);
+        }
+
+        let (x, y, luminance) = Color::default().to_xyy(ColorSpace::Rec2020, WhitePoint::D65);
+
+        assert_eq!((x, y, luminance), (0.3127, 0.329, 0.0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Color {
+            r: 0.0,
+            g: 0.5,
+            b: 1.0,
+        };
+        let b = Color {
+            r: 1.0,
+            g: 0.5,
+            b: 0.0,
+        };
+
+        assert_eq!(Color::lerp(&a, &b, 0.0), a);
+        assert_eq!(Color::lerp(&a, &b, 1.0), b);
+        assert_eq!(
+            a.lerp(&b, 0.25),
+            Color {
+                r: 0.25,
+                g: 0.5,
+                b: 0.75
+            }
+        );
+    }
+
+    #[test]
+    fn test_linear_rgba_display_and_from_str() {
+        let c = LinearRgba::new(0.25, 0.5, 1.0, 0.75);
+
+        assert_eq!(c.to_string(), "(0.25, 0.5, 1, 0.75)");
+        assert_eq!(c.to_string().parse(), Ok(c));
+        assert!("(0.25, 0.5, 1)".parse::<LinearRgba>().is_err());
+    }
 }
@@ -0,0 +1,95 @@
+use std::{error, fmt, num::ParseFloatError};
+
+/// Error returned when an input has fewer elements than needed, e.g. when an
+/// iterator passed to [`Vector3::try_from_iter`] runs out early or a byte
+/// buffer passed to [`EndianBytes`] methods is too short.
+///
+/// [`Vector3::try_from_iter`]: super::Vector3::try_from_iter
+/// [`EndianBytes`]: super::EndianBytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotEnoughElementsError {
+    /// The number of elements needed.
+    pub expected: usize,
+    /// The number of elements available.
+    pub found: usize,
+}
+
+unsafe impl Send for NotEnoughElementsError {}
+unsafe impl Sync for NotEnoughElementsError {}
+
+impl fmt::Display for NotEnoughElementsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} elements, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl error::Error for NotEnoughElementsError {}
+
+/// Error returned when parsing a value from a string fails, e.g. by the
+/// [`FromStr`](std::str::FromStr) implementation of [`Vector3`].
+///
+/// [`Vector3`]: super::Vector3
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string has the wrong number of components.
+    ComponentCount {
+        /// The number of components needed.
+        expected: usize,
+        /// The number of components in the string.
+        found: usize,
+    },
+    /// The string has the wrong number of rows, e.g. for a matrix.
+    RowCount {
+        /// The number of rows needed.
+        expected: usize,
+        /// The number of rows in the string.
+        found: usize,
+    },
+    /// A component is not a valid number.
+    InvalidNumber(ParseFloatError),
+    /// The string is not a hex color of the form `#RRGGBB` or `#RRGGBBAA`.
+    InvalidHex,
+    /// The string is not an Euler angle order, e.g. `XYZ`.
+    InvalidEulerOrder,
+}
+
+unsafe impl Send for ParseError {}
+unsafe impl Sync for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ComponentCount { expected, found } => {
+                write!(f, "expected {expected} components, found {found}")
+            }
+            Self::RowCount { expected, found } => {
+                write!(f, "expected {expected} rows, found {found}")
+            }
+            Self::InvalidNumber(e) => write!(f, "invalid component: {e}"),
+            Self::InvalidHex => write!(f, "expected a hex color like #RRGGBB or #RRGGBBAA"),
+            Self::InvalidEulerOrder => write!(f, "expected an Euler angle order like XYZ"),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::ComponentCount { .. }
+            | Self::RowCount { .. }
+            | Self::InvalidHex
+            | Self::InvalidEulerOrder => None,
+            Self::InvalidNumber(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseError {
+    fn from(e: ParseFloatError) -> Self {
+        Self::InvalidNumber(e)
+    }
+}
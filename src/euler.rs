@@ -1,10 +1,16 @@
-use super::{Matrix4, Quaternion};
+use core::f32::consts::{FRAC_PI_2, PI};
+use std::{fmt, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+use crate::linalg::wrap_angle;
+
+use super::{Matrix4, ParseError, Quaternion};
 
 /// Order of Euler rotations.
 ///
 /// For example, the XYZ order ([`Self::Xyz`]) means the rotation around the
 /// local X axis is applied first, then Y, then Z.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EulerOrder {
     Xyz,
     Xzy,
@@ -24,6 +30,38 @@ impl Default for EulerOrder {
     }
 }
 
+impl fmt::Display for EulerOrder {
+    /// Formats this order as the axes in uppercase, e.g. `XYZ`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Xyz => "XYZ",
+            Self::Xzy => "XZY",
+            Self::Yxz => "YXZ",
+            Self::Yzx => "YZX",
+            Self::Zxy => "ZXY",
+            Self::Zyx => "ZYX",
+        })
+    }
+}
+
+impl FromStr for EulerOrder {
+    type Err = ParseError;
+
+    /// Parses an order in the format written by `Display`, e.g. `XYZ`. The
+    /// axes are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "XYZ" => Ok(Self::Xyz),
+            "XZY" => Ok(Self::Xzy),
+            "YXZ" => Ok(Self::Yxz),
+            "YZX" => Ok(Self::Yzx),
+            "ZXY" => Ok(Self::Zxy),
+            "ZYX" => Ok(Self::Zyx),
+            _ => Err(ParseError::InvalidEulerOrder),
+        }
+    }
+}
+
 /// Euler angles, which describes rotations as chained rotations around the
 /// local XYZ axes.
 ///
@@ -33,7 +71,10 @@ impl Default for EulerOrder {
 ///
 /// For a better representation of rotations, use [`Quaternion`], which
 /// represents a rotation around an arbitrary axis.
-#[derive(Default)]
+///
+/// You can convert a tuple or an array of three angles in radians to Euler
+/// angles in the default order using `.into()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Euler {
     /// Angle of the X axis in radians.
     pub x: f32,
@@ -45,9 +86,98 @@ pub struct Euler {
     pub order: EulerOrder,
 }
 
+unsafe impl Send for Euler {}
+unsafe impl Sync for Euler {}
+
+impl fmt::Display for Euler {
+    /// Formats these angles as `XYZ(x, y, z)`, where `XYZ` is the order.
+    ///
+    /// The precision, if any, applies to each angle, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.order)?;
+        write_components(f, &[self.x, self.y, self.z])
+    }
+}
+
+impl FromStr for Euler {
+    type Err = ParseError;
+
+    /// Parses angles in the format written by `Display`, e.g. `XYZ(x, y, z)`.
+    /// Without an order, e.g. `(x, y, z)`, the default order is used.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let (order, angles) = match s.find('(') {
+            Some(0) | None => (EulerOrder::default(), s),
+            Some(i) => (s[..i].parse()?, &s[i..]),
+        };
+        let [x, y, z] = parse_components::<f32, 3>(angles)?;
+
+        Ok(Self { x, y, z, order })
+    }
+}
+
+impl From<(f32, f32, f32)> for Euler {
+    fn from(tuple: (f32, f32, f32)) -> Self {
+        Euler {
+            x: tuple.0,
+            y: tuple.1,
+            z: tuple.2,
+            order: EulerOrder::default(),
+        }
+    }
+}
+
+impl From<[f32; 3]> for Euler {
+    fn from(array: [f32; 3]) -> Self {
+        Euler {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+            order: EulerOrder::default(),
+        }
+    }
+}
+
 impl Euler {
+    /// Creates Euler angles from the given angles in radians and axis order.
+    pub fn new(x: f32, y: f32, z: f32, order: EulerOrder) -> Self {
+        Self { x, y, z, order }
+    }
+
+    /// Creates Euler angles from the given angles in degrees and axis order.
+    pub fn from_degrees(x: f32, y: f32, z: f32, order: EulerOrder) -> Self {
+        Self {
+            x: x.to_radians(),
+            y: y.to_radians(),
+            z: z.to_radians(),
+            order,
+        }
+    }
+
     /// Creates Euler angles from the given rotation matrix and axis order.
+    ///
+    /// If the rotation is in gimbal lock, the first and last axes rotate about
+    /// the same axis, so only their combined angle is known. In that case the
+    /// angle of the last axis is set to zero; use
+    /// [`Euler::try_from_rotation_matrix`] to detect this.
     pub fn from_rotation_matrix(m: &Matrix4, order: EulerOrder) -> Self {
+        Self::decompose(m, order).0
+    }
+
+    /// Creates Euler angles from the given rotation matrix and axis order, or
+    /// returns `None` if the rotation is in gimbal lock, e.g. so that calling
+    /// code can keep the previous angles instead of snapping one of them to
+    /// zero.
+    pub fn try_from_rotation_matrix(m: &Matrix4, order: EulerOrder) -> Option<Self> {
+        match Self::decompose(m, order) {
+            (euler, false) => Some(euler),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns the Euler angles of the given rotation matrix in the given axis
+    /// order, and whether the rotation is in gimbal lock.
+    fn decompose(m: &Matrix4, order: EulerOrder) -> (Self, bool) {
         // Extract the top-left 3x3 matrix.
 
         let m11 = m.elements[0];
@@ -63,68 +193,79 @@ impl Euler {
         match order {
             EulerOrder::Xyz => {
                 let y = m13.clamp(-1.0, 1.0).asin();
-                let (x, z) = if m13.abs() < 0.9999999 {
+                let locked = m13.abs() >= 0.9999999;
+                let (x, z) = if !locked {
                     ((-m23).atan2(m33), (-m12).atan2(m11))
                 } else {
                     (m32.atan2(m22), 0.0)
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
             EulerOrder::Xzy => {
                 let z = (-(m12.clamp(-1.0, 1.0))).asin();
 
-                let (x, y) = if m12.abs() < 0.9999999 {
+                let locked = m12.abs() >= 0.9999999;
+
+                let (x, y) = if !locked {
                     (m32.atan2(m22), m13.atan2(m11))
                 } else {
                     ((-m23).atan2(m33), 0.0)
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
             EulerOrder::Yxz => {
                 let x = (-(m23.clamp(-1.0, 1.0))).asin();
 
-                let (y, z) = if m23.abs() < 0.9999999 {
+                let locked = m23.abs() >= 0.9999999;
+
+                let (y, z) = if !locked {
                     (m13.atan2(m33), m21.atan2(m22))
                 } else {
                     ((-m31).atan2(m11), 0.0)
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
             EulerOrder::Yzx => {
                 let z = m21.clamp(-1.0, 1.0).asin();
 
-                let (x, y) = if m21.abs() < 0.9999999 {
+                let locked = m21.abs() >= 0.9999999;
+
+                let (x, y) = if !locked {
                     ((-m23).atan2(m22), (-m31).atan2(m11))
                 } else {
                     (0.0, m13.atan2(m33))
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
             EulerOrder::Zxy => {
                 let x = m32.clamp(-1.0, 1.0).asin();
 
-                let (y, z) = if m32.abs() < 0.9999999 {
+                let locked = m32.abs() >= 0.9999999;
+
+                let (y, z) = if !locked {
                     ((-m31).atan2(m33), (-m12).atan2(m22))
                 } else {
                     (0.0, m21.atan2(m11))
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
             EulerOrder::Zyx => {
                 let y = (-(m31.clamp(-1.0, 1.0))).asin();
 
-                let (x, z) = if m31.abs() < 0.9999999 {
+                let locked = m31.abs() >= 0.9999999;
+
+                let (x, z) = if !locked {
                     (m32.atan2(m33), m21.atan2(m11))
                 } else {
                     (0.0, (-m12).atan2(m22))
                 };
 
-                Self { x, y, z, order }
+                (Self { x, y, z, order }, locked)
             }
         }
     }
@@ -134,6 +275,87 @@ impl Euler {
         Self::from_rotation_matrix(&Matrix4::from_quaternion(q), order)
     }
 
+    /// Returns the Euler angles in the given axis order that represent the
+    /// same rotation as these angles, e.g. when importing content authored
+    /// with a different convention.
+    pub fn reordered(&self, order: EulerOrder) -> Self {
+        Self::from_quaternion(&Quaternion::from(self), order)
+    }
+
+    /// Returns the X, Y, and Z angles in degrees.
+    pub fn to_degrees(&self) -> (f32, f32, f32) {
+        (
+            self.x.to_degrees(),
+            self.y.to_degrees(),
+            self.z.to_degrees(),
+        )
+    }
+
+    /// Returns the X, Y, and Z angles in radians.
+    pub fn to_radians(&self) -> (f32, f32, f32) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Returns whether these angles are within `epsilon` radians of gimbal
+    /// lock, i.e. the angle of the middle axis of the order (e.g. Y for XYZ)
+    /// is close to `±π/2`. Near gimbal lock, the first and last axes rotate
+    /// about nearly the same axis, so one degree of freedom is lost.
+    pub fn is_gimbal_locked(&self, epsilon: f32) -> bool {
+        let middle = match self.order {
+            EulerOrder::Xyz | EulerOrder::Zyx => self.y,
+            EulerOrder::Xzy | EulerOrder::Yzx => self.z,
+            EulerOrder::Yxz | EulerOrder::Zxy => self.x,
+        };
+
+        (wrap_angle(middle).abs() - FRAC_PI_2).abs() <= epsilon
+    }
+
+    /// Returns equivalent Euler angles with every angle in `(-π, π]` and the
+    /// angle of the middle axis of the order (e.g. Y for XYZ) in
+    /// `[-π/2, π/2]`, which is the range that conversion from a rotation
+    /// matrix or quaternion produces.
+    ///
+    /// Equivalent rotations then have comparable angles, e.g. when diffing
+    /// animations or displaying rotations in a UI.
+    pub fn wrapped(&self) -> Self {
+        let (mut first, mut middle, mut last) = match self.order {
+            EulerOrder::Xyz => (self.x, self.y, self.z),
+            EulerOrder::Xzy => (self.x, self.z, self.y),
+            EulerOrder::Yxz => (self.y, self.x, self.z),
+            EulerOrder::Yzx => (self.y, self.z, self.x),
+            EulerOrder::Zxy => (self.z, self.x, self.y),
+            EulerOrder::Zyx => (self.z, self.y, self.x),
+        };
+
+        middle = wrap_angle(middle);
+
+        // Rotating the first and last axes by a half turn flips the middle
+        // axis, which brings it back into [-π/2, π/2].
+        if middle.abs() > FRAC_PI_2 {
+            first += PI;
+            middle = PI.copysign(middle) - middle;
+            last += PI;
+        }
+
+        let (first, last) = (wrap_angle(first), wrap_angle(last));
+
+        let (x, y, z) = match self.order {
+            EulerOrder::Xyz => (first, middle, last),
+            EulerOrder::Xzy => (first, last, middle),
+            EulerOrder::Yxz => (middle, first, last),
+            EulerOrder::Yzx => (last, first, middle),
+            EulerOrder::Zxy => (middle, last, first),
+            EulerOrder::Zyx => (last, middle, first),
+        };
+
+        Self {
+            x,
+            y,
+            z,
+            order: self.order,
+        }
+    }
+
     /// Sets the X, Y, and Z angles, and optionally the order.
     pub fn set(&mut self, x: f32, y: f32, z: f32, order: Option<EulerOrder>) {
         self.x = x;
@@ -145,3 +367,179 @@ impl Euler {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts;
+
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_new_and_from() {
+        let expected = Euler {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            order: EulerOrder::Xyz,
+        };
+
+        assert_eq!(Euler::new(1.0, 2.0, 3.0, EulerOrder::Xyz), expected);
+        assert_eq!(Euler::from((1.0, 2.0, 3.0)), expected);
+        assert_eq!(Euler::from([1.0, 2.0, 3.0]), expected);
+    }
+
+    #[test]
+    fn test_degrees() {
+        let e = Euler::from_degrees(90.0, -45.0, 180.0, EulerOrder::Yxz);
+
+        assert_float_absolute_eq!(e.x, consts::FRAC_PI_2);
+        assert_float_absolute_eq!(e.y, -consts::FRAC_PI_4);
+        assert_float_absolute_eq!(e.z, consts::PI);
+        assert_eq!(e.order, EulerOrder::Yxz);
+
+        let (x, y, z) = e.to_degrees();
+
+        assert_float_absolute_eq!(x, 90.0, 1e-4);
+        assert_float_absolute_eq!(y, -45.0, 1e-4);
+        assert_float_absolute_eq!(z, 180.0, 1e-4);
+        assert_eq!(e.to_radians(), (e.x, e.y, e.z));
+    }
+
+    #[test]
+    fn test_wrapped() {
+        let orders = [
+            EulerOrder::Xyz,
+            EulerOrder::Xzy,
+            EulerOrder::Yxz,
+            EulerOrder::Yzx,
+            EulerOrder::Zxy,
+            EulerOrder::Zyx,
+        ];
+
+        for order in orders {
+            let e = Euler {
+                x: 7.0,
+                y: 2.5,
+                z: -4.0,
+                order,
+            };
+            let wrapped = e.wrapped();
+
+            for angle in [wrapped.x, wrapped.y, wrapped.z] {
+                assert!(angle > -consts::PI && angle <= consts::PI);
+            }
+
+            let expected = Matrix4::from_euler(&e);
+            let actual = Matrix4::from_euler(&wrapped);
+
+            for i in 0..16 {
+                assert_float_absolute_eq!(actual.elements[i], expected.elements[i], 1e-5);
+            }
+
+            let roundtrip = Euler::from_rotation_matrix(&actual, order);
+
+            assert_float_absolute_eq!(roundtrip.x, wrapped.x, 1e-4);
+            assert_float_absolute_eq!(roundtrip.y, wrapped.y, 1e-4);
+            assert_float_absolute_eq!(roundtrip.z, wrapped.z, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_reordered() {
+        let e = Euler {
+            x: 0.3,
+            y: -0.6,
+            z: 1.1,
+            order: EulerOrder::Xyz,
+        };
+
+        let reordered = e.reordered(EulerOrder::Zxy);
+
+        assert_eq!(reordered.order, EulerOrder::Zxy);
+
+        let expected = Matrix4::from_euler(&e);
+        let actual = Matrix4::from_euler(&reordered);
+
+        for i in 0..16 {
+            assert_float_absolute_eq!(actual.elements[i], expected.elements[i], 1e-5);
+        }
+
+        let roundtrip = reordered.reordered(EulerOrder::Xyz);
+
+        assert_float_absolute_eq!(roundtrip.x, e.x, 1e-5);
+        assert_float_absolute_eq!(roundtrip.y, e.y, 1e-5);
+        assert_float_absolute_eq!(roundtrip.z, e.z, 1e-5);
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        let locked = Euler {
+            x: 0.4,
+            y: consts::FRAC_PI_2,
+            z: 0.0,
+            order: EulerOrder::Xyz,
+        };
+        let free = Euler { y: 1.0, ..locked };
+
+        assert!(locked.is_gimbal_locked(1e-3));
+        assert!(!free.is_gimbal_locked(1e-3));
+        assert!(Euler {
+            y: -consts::FRAC_PI_2 + consts::TAU,
+            ..locked
+        }
+        .is_gimbal_locked(1e-3));
+        assert!(!Euler {
+            order: EulerOrder::Yxz,
+            ..locked
+        }
+        .is_gimbal_locked(1e-3));
+
+        let m = Matrix4::from_euler(&locked);
+
+        assert_eq!(Euler::try_from_rotation_matrix(&m, EulerOrder::Xyz), None);
+
+        let m = Matrix4::from_euler(&free);
+        let e = Euler::try_from_rotation_matrix(&m, EulerOrder::Xyz).unwrap();
+
+        assert_eq!(e, Euler::from_rotation_matrix(&m, EulerOrder::Xyz));
+        assert_float_absolute_eq!(e.y, 1.0, 1e-5);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut a = Euler::default();
+        let b = a;
+
+        a.set(1.0, 2.0, 3.0, None);
+
+        assert_ne!(a, b);
+        assert_eq!(
+            a,
+            Euler {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                order: EulerOrder::Xyz,
+            }
+        );
+
+        a.set(1.0, 2.0, 3.0, Some(EulerOrder::Zyx));
+
+        assert_eq!(a.order, EulerOrder::Zyx);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let e = Euler::new(0.5, -1.0, 2.0, EulerOrder::Zyx);
+
+        assert_eq!(e.to_string(), "ZYX(0.5, -1, 2)");
+        assert_eq!(e.to_string().parse(), Ok(e));
+        assert_eq!("(0.5, -1, 2)".parse(), Ok(Euler::from((0.5, -1.0, 2.0))));
+        assert_eq!(
+            "XXY(0.5, -1, 2)".parse::<Euler>(),
+            Err(ParseError::InvalidEulerOrder)
+        );
+    }
+}
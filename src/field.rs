@@ -0,0 +1,87 @@
+use super::Vector3;
+
+/// Returns the gradient of the scalar field `f` at `point`, estimated with
+/// central differences of step `h`, e.g. to estimate the normal of a signed
+/// distance field.
+///
+/// Smaller steps are more accurate for smooth fields but lose precision to
+/// rounding, so `h` should be small relative to the features of the field
+/// but not close to the precision of `f32`.
+pub fn gradient(f: impl Fn(&Vector3) -> f32, point: &Vector3, h: f32) -> Vector3 {
+    let partial = |offset: Vector3| (f(&(point + offset)) - f(&(point - offset))) / (2.0 * h);
+
+    Vector3 {
+        x: partial((h, 0.0, 0.0).into()),
+        y: partial((0.0, h, 0.0).into()),
+        z: partial((0.0, 0.0, h).into()),
+    }
+}
+
+/// Returns the curl of the vector field `f` at `point`, estimated with
+/// central differences of step `h`, e.g. to verify the rotation of a
+/// procedural velocity field that drives particles.
+///
+/// See [`gradient`] for how to choose `h`.
+pub fn curl(f: impl Fn(&Vector3) -> Vector3, point: &Vector3, h: f32) -> Vector3 {
+    let partial = |offset: Vector3| (f(&(point + offset)) - f(&(point - offset))) / (2.0 * h);
+
+    let dx = partial((h, 0.0, 0.0).into());
+    let dy = partial((0.0, h, 0.0).into());
+    let dz = partial((0.0, 0.0, h).into());
+
+    Vector3 {
+        x: dy.z - dz.y,
+        y: dz.x - dx.z,
+        z: dx.y - dy.x,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        // Signed distance to the unit sphere.
+        let sphere = |p: &Vector3| p.length() - 1.0;
+        let normal = gradient(sphere, &(0.0, 0.6, 0.8).into(), 1e-3);
+
+        assert_float_absolute_eq!(normal.x, 0.0, 1e-3);
+        assert_float_absolute_eq!(normal.y, 0.6, 1e-3);
+        assert_float_absolute_eq!(normal.z, 0.8, 1e-3);
+
+        let g = gradient(
+            |p| p.x * p.x + 3.0 * p.y * p.z,
+            &(1.0, 2.0, -1.0).into(),
+            1e-2,
+        );
+
+        assert_float_absolute_eq!(g.x, 2.0, 1e-3);
+        assert_float_absolute_eq!(g.y, -3.0, 1e-3);
+        assert_float_absolute_eq!(g.z, 6.0, 1e-3);
+    }
+
+    #[test]
+    fn test_curl() {
+        // Rigid rotation about Z with angular velocity 1 has a curl of 2 Z.
+        let rotation = |p: &Vector3| Vector3::from((-p.y, p.x, 0.0));
+        let c = curl(rotation, &(0.3, -0.2, 0.5).into(), 1e-2);
+
+        assert_float_absolute_eq!(c.x, 0.0, 1e-3);
+        assert_float_absolute_eq!(c.y, 0.0, 1e-3);
+        assert_float_absolute_eq!(c.z, 2.0, 1e-3);
+
+        // Gradient fields have no curl.
+        let c = curl(
+            |p| (2.0 * p.x, 3.0 * p.z, 3.0 * p.y).into(),
+            &(1.0, 1.0, 1.0).into(),
+            1e-2,
+        );
+
+        assert_float_absolute_eq!(c.x, 0.0, 1e-3);
+        assert_float_absolute_eq!(c.y, 0.0, 1e-3);
+        assert_float_absolute_eq!(c.z, 0.0, 1e-3);
+    }
+}
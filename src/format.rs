@@ -0,0 +1,178 @@
+//! Internal helpers for the [`Display`](fmt::Display) and
+//! [`FromStr`](std::str::FromStr) implementations of the small value types,
+//! which use the compact format `(x, y, z)`.
+
+use std::{fmt, str::FromStr};
+
+use super::ParseError;
+
+/// Writes the given components as `(a, b, c)`, honoring the precision of the
+/// formatter, e.g. `{:.2}`.
+pub(crate) fn write_components<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    components: &[T],
+) -> fmt::Result {
+    f.write_str("(")?;
+
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        match f.precision() {
+            Some(precision) => write!(f, "{c:.precision$}")?,
+            None => write!(f, "{c}")?,
+        }
+    }
+
+    f.write_str(")")
+}
+
+/// Parses exactly `N` comma-separated components, optionally enclosed in
+/// parentheses and surrounded by whitespace, e.g. `(1, 2.5, -3)` or `1,2.5,-3`.
+pub(crate) fn parse_components<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr<Err = std::num::ParseFloatError> + Copy + Default,
+{
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or(s);
+
+    let found = if s.trim().is_empty() {
+        0
+    } else {
+        s.split(',').count()
+    };
+
+    if found != N {
+        return Err(ParseError::ComponentCount { expected: N, found });
+    }
+
+    let mut components = [T::default(); N];
+
+    for (component, part) in components.iter_mut().zip(s.split(',')) {
+        *component = part.trim().parse()?;
+    }
+
+    Ok(components)
+}
+
+/// Writes the given rows as `((a, b), (c, d))`, honoring the precision of the
+/// formatter like [`write_components`].
+pub(crate) fn write_rows<T: fmt::Display, const C: usize>(
+    f: &mut fmt::Formatter<'_>,
+    rows: &[[T; C]],
+) -> fmt::Result {
+    f.write_str("(")?;
+
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        write_components(f, row)?;
+    }
+
+    f.write_str(")")
+}
+
+/// Parses exactly `R` rows of `C` comma-separated components, where each row
+/// is enclosed in parentheses and the rows are optionally enclosed in another
+/// pair of parentheses, e.g. `((1, 2), (3, 4))` or `(1, 2), (3, 4)`.
+pub(crate) fn parse_rows<T, const R: usize, const C: usize>(
+    s: &str,
+) -> Result<[[T; C]; R], ParseError>
+where
+    T: FromStr<Err = std::num::ParseFloatError> + Copy + Default,
+{
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .filter(|s| s.trim_start().starts_with('('))
+        .unwrap_or(s);
+
+    let rows: Vec<&str> = s
+        .split(')')
+        .map(|row| row.trim_start().trim_start_matches(',').trim())
+        .filter(|row| !row.is_empty())
+        .collect();
+
+    if rows.len() != R {
+        return Err(ParseError::RowCount {
+            expected: R,
+            found: rows.len(),
+        });
+    }
+
+    let mut components = [[T::default(); C]; R];
+
+    for (row, part) in components.iter_mut().zip(rows) {
+        *row = parse_components(part.strip_prefix('(').unwrap_or(part))?;
+    }
+
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_components() {
+        assert_eq!(
+            parse_components::<f32, 3>("(1, 2.5, -3)"),
+            Ok([1.0, 2.5, -3.0])
+        );
+        assert_eq!(
+            parse_components::<f32, 3>(" 1,2.5 , -3 "),
+            Ok([1.0, 2.5, -3.0])
+        );
+        assert_eq!(
+            parse_components::<f32, 3>("(1, 2)"),
+            Err(ParseError::ComponentCount {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            parse_components::<f32, 2>("()"),
+            Err(ParseError::ComponentCount {
+                expected: 2,
+                found: 0
+            })
+        );
+        assert!(matches!(
+            parse_components::<f32, 2>("(1, x)"),
+            Err(ParseError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let expected = Ok([[1.0, 2.0], [3.0, -4.5]]);
+
+        assert_eq!(parse_rows::<f32, 2, 2>("((1, 2), (3, -4.5))"), expected);
+        assert_eq!(parse_rows::<f32, 2, 2>(" (1,2),(3 , -4.5) "), expected);
+        assert_eq!(
+            parse_rows::<f32, 2, 2>("(1, 2, 3, 4)"),
+            Err(ParseError::RowCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            parse_rows::<f32, 2, 2>("((1, 2), (3))"),
+            Err(ParseError::ComponentCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            parse_rows::<f32, 2, 2>("((1, 2), (3, x))"),
+            Err(ParseError::InvalidNumber(_))
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+use super::Color;
+
+/// Color space in which a [`Gradient`] interpolates between its stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientSpace {
+    /// Interpolates the linear channels, which is physically correct, e.g.
+    /// for light, but makes dark colors dominate.
+    LinearRgb,
+    /// Interpolates the sRGB-encoded channels, which matches CSS gradients
+    /// and most image editors.
+    Srgb,
+    /// Interpolates in OKLab, which is perceptually even. See
+    /// [`Color::lerp_oklab`].
+    Oklab,
+}
+
+unsafe impl Send for GradientSpace {}
+unsafe impl Sync for GradientSpace {}
+
+impl Default for GradientSpace {
+    /// Returns the default space, which is OKLab.
+    fn default() -> Self {
+        Self::Oklab
+    }
+}
+
+/// Multi-stop color gradient, e.g. for heatmaps, skyboxes, and the colors of
+/// particles over their lifetime.
+///
+/// Each stop is a position and a color in linear sRGB. The stops are kept
+/// sorted by position. Stops at the same position make a hard edge.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f64, Color)>,
+    /// The color space to interpolate in.
+    pub space: GradientSpace,
+}
+
+unsafe impl Send for Gradient {}
+unsafe impl Sync for Gradient {}
+
+impl Gradient {
+    /// Creates a gradient with the given stops, in any order, that
+    /// interpolates in the given color space.
+    pub fn new(stops: impl IntoIterator<Item = (f64, Color)>, space: GradientSpace) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+
+        // The sort is stable, so stops at the same position keep their order.
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self { stops, space }
+    }
+
+    /// Returns the stops of this gradient, sorted by position.
+    pub fn stops(&self) -> &[(f64, Color)] {
+        &self.stops
+    }
+
+    /// Adds a stop to this gradient, after any stops at the same position.
+    pub fn add_stop(&mut self, position: f64, color: Color) {
+        let index = self.stops.partition_point(|stop| stop.0 <= position);
+
+        self.stops.insert(index, (position, color));
+    }
+
+    /// Returns the color of this gradient at the given position.
+    ///
+    /// Positions before the first stop or after the last stop get the color of
+    /// that stop. A gradient without stops is black everywhere.
+    pub fn sample(&self, t: f64) -> Color {
+        let index = self.stops.partition_point(|stop| stop.0 <= t);
+
+        let (start, end) = match (index.checked_sub(1), self.stops.get(index)) {
+            (Some(i), Some(end)) => (self.stops[i], *end),
+            (Some(i), None) => return self.stops[i].1,
+            (None, Some(end)) => return end.1,
+            (None, None) => return Color::default(),
+        };
+
+        let factor = (t - start.0) / (end.0 - start.0);
+
+        match self.space {
+            GradientSpace::LinearRgb => start.1.lerp(&end.1, factor),
+            GradientSpace::Srgb => start.1.to_srgb().lerp(&end.1.to_srgb(), factor).to_linear(),
+            GradientSpace::Oklab => start.1.lerp_oklab(&end.1, factor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    const RED: Color = Color {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+    };
+    const BLUE: Color = Color {
+        r: 0.0,
+        g: 0.0,
+        b: 1.0,
+    };
+    const WHITE: Color = Color {
+        r: 1.0,
+        g: 1.0,
+        b: 1.0,
+    };
+
+    #[test]
+    fn test_sample() {
+        let gradient = Gradient::new([(1.0, BLUE), (0.0, RED)], GradientSpace::LinearRgb);
+
+        assert_eq!(gradient.stops()[0], (0.0, RED));
+        assert_eq!(gradient.sample(-1.0), RED);
+        assert_eq!(gradient.sample(0.0), RED);
+        assert_eq!(gradient.sample(1.0), BLUE);
+        assert_eq!(gradient.sample(2.0), BLUE);
+        assert_eq!(
+            gradient.sample(0.25),
+            Color {
+                r: 0.75,
+                g: 0.0,
+                b: 0.25
+            }
+        );
+
+        assert_eq!(Gradient::default().sample(0.5), Color::default());
+    }
+
+    #[test]
+    fn test_sample_spaces() {
+        let black = Color::default();
+
+        let linear = Gradient::new([(0.0, black), (1.0, WHITE)], GradientSpace::LinearRgb);
+        let srgb = Gradient::new([(0.0, black), (1.0, WHITE)], GradientSpace::Srgb);
+        let oklab = Gradient::new([(0.0, black), (1.0, WHITE)], GradientSpace::Oklab);
+
+        assert_float_absolute_eq!(linear.sample(0.5).r, 0.5, 1e-12);
+        assert_float_absolute_eq!(srgb.sample(0.5).to_srgb().r, 0.5, 1e-12);
+        assert_float_absolute_eq!(oklab.sample(0.5).to_oklab().0, 0.5, 1e-6);
+    }
+
+    #[test]
+    fn test_add_stop_hard_edge() {
+        let mut gradient = Gradient::new([(0.0, RED), (1.0, RED)], GradientSpace::LinearRgb);
+
+        gradient.add_stop(0.5, RED);
+        gradient.add_stop(0.5, BLUE);
+        gradient.add_stop(1.0, BLUE);
+
+        assert_eq!(gradient.stops().len(), 5);
+        assert_eq!(gradient.sample(0.4), RED);
+        assert_eq!(gradient.sample(0.5), BLUE);
+        assert_eq!(gradient.sample(1.0), BLUE);
+    }
+}
@@ -0,0 +1,110 @@
+use super::Matrix4;
+
+/// Contiguous buffer of joint (bone) matrices for GPU skinning, laid out so
+/// that it can be uploaded as is to a storage or uniform buffer of
+/// `array<mat4x4<f32>>`.
+///
+/// Each skinning matrix is the product `world * inverse_bind` of the world
+/// transform of a joint and the inverse of its bind pose transform, i.e. a
+/// vertex is first taken from bind pose space to joint space, then to world
+/// space.
+#[derive(Debug, Clone, Default)]
+pub struct JointPalette {
+    matrices: Vec<Matrix4>,
+}
+
+unsafe impl Send for JointPalette {}
+unsafe impl Sync for JointPalette {}
+
+impl JointPalette {
+    /// Creates a palette of `joint_count` identity matrices.
+    pub fn new(joint_count: usize) -> Self {
+        Self {
+            matrices: vec![Matrix4::identity(); joint_count],
+        }
+    }
+
+    /// Returns the number of joints.
+    pub fn len(&self) -> usize {
+        self.matrices.len()
+    }
+
+    /// Returns whether the palette has no joints.
+    pub fn is_empty(&self) -> bool {
+        self.matrices.is_empty()
+    }
+
+    /// Recomputes every skinning matrix as `world[i] * inverse_bind[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inverse_bind` or `world` does not have one matrix per joint.
+    pub fn update(&mut self, inverse_bind: &[Matrix4], world: &[Matrix4]) {
+        assert!(
+            inverse_bind.len() == self.len() && world.len() == self.len(),
+            "expected one matrix per joint"
+        );
+
+        for ((m, inverse_bind), world) in self.matrices.iter_mut().zip(inverse_bind).zip(world) {
+            *m = world * inverse_bind;
+        }
+    }
+
+    /// Returns the skinning matrices.
+    pub fn matrices(&self) -> &[Matrix4] {
+        &self.matrices
+    }
+
+    /// Returns the skinning matrices as mutable, e.g. to set them directly.
+    pub fn matrices_mut(&mut self) -> &mut [Matrix4] {
+        &mut self.matrices
+    }
+
+    /// Returns the skinning matrices as bytes, ready to be written to a GPU
+    /// buffer.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.matrices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let inverse_bind = [
+            Matrix4::identity(),
+            Matrix4::from_translation(&(0.0, -1.0, 0.0).into()),
+        ];
+        let world = [
+            Matrix4::from_translation(&(5.0, 0.0, 0.0).into()),
+            Matrix4::from_translation(&(5.0, 1.0, 0.0).into()),
+        ];
+        let mut palette = JointPalette::new(2);
+
+        palette.update(&inverse_bind, &world);
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.matrices()[0].translation(), (5.0, 0.0, 0.0).into());
+        assert_eq!(palette.matrices()[1].translation(), (5.0, 0.0, 0.0).into());
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        let mut palette = JointPalette::new(3);
+
+        palette.matrices_mut()[1] = Matrix4::from_translation(&(1.0, 2.0, 3.0).into());
+
+        let bytes = palette.as_bytes();
+
+        assert_eq!(bytes.len(), 3 * 64);
+        assert_eq!(bytes[64 + 48..64 + 52], 1.0f32.to_ne_bytes());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_update_length_mismatch() {
+        JointPalette::new(2).update(&[Matrix4::identity()], &[Matrix4::identity(); 2]);
+    }
+}
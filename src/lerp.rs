@@ -0,0 +1,195 @@
+use super::{Color, Vector2, Vector3, Vector4};
+
+/// Linear interpolation between values of the same type, e.g. vertex
+/// attributes.
+pub trait Lerp: Sized {
+    /// Returns the linear interpolation between this value and `other` by the
+    /// factor `t`, where `t = 0.0` returns this value and `t = 1.0` returns
+    /// `other`. `t` is not clamped.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+
+    /// Returns the weighted sum `a * w.x + b * w.y + c * w.z` of three values.
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self {
+        a * w.x + b * w.y + c * w.z
+    }
+}
+
+impl Lerp for Vector2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vector2 {
+            x: self.x.lerp(&other.x, t),
+            y: self.y.lerp(&other.y, t),
+        }
+    }
+
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self {
+        Vector2 {
+            x: f32::weighted_sum(&a.x, &b.x, &c.x, w),
+            y: f32::weighted_sum(&a.y, &b.y, &c.y, w),
+        }
+    }
+}
+
+impl Lerp for Vector3 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vector3::lerp(self, other, t)
+    }
+
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self {
+        a * w.x + b * w.y + c * w.z
+    }
+}
+
+impl Lerp for Vector4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.zip_with(other, |a, b| a.lerp(&b, t))
+    }
+
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self {
+        Vector4 {
+            x: f32::weighted_sum(&a.x, &b.x, &c.x, w),
+            y: f32::weighted_sum(&a.y, &b.y, &c.y, w),
+            z: f32::weighted_sum(&a.z, &b.z, &c.z, w),
+            w: f32::weighted_sum(&a.w, &b.w, &c.w, w),
+        }
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Color::lerp(self, other, t as f64)
+    }
+
+    fn weighted_sum(a: &Self, b: &Self, c: &Self, w: &Vector3) -> Self {
+        let (u, v, w) = (w.x as f64, w.y as f64, w.z as f64);
+
+        Color {
+            r: a.r * u + b.r * v + c.r * w,
+            g: a.g * u + b.g * v + c.g * w,
+            b: a.b * u + b.b * v + c.b * w,
+        }
+    }
+}
+
+/// Returns the attribute at the point with barycentric coordinates `uvw` in
+/// the triangle whose corners have the attributes `a`, `b`, and `c`, e.g. to
+/// interpolate the normal or UV at a ray hit.
+///
+/// The coordinates should sum to 1. They may be negative for points outside
+/// the triangle.
+pub fn interpolate_barycentric<T: Lerp>(a: &T, b: &T, c: &T, uvw: &Vector3) -> T {
+    T::weighted_sum(a, b, c, uvw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(2.0.lerp(&4.0, 0.25), 2.5);
+        assert_eq!(
+            Vector2::from((0.0, 2.0)).lerp(&(2.0, 4.0).into(), 0.5),
+            (1.0, 3.0).into()
+        );
+        assert_eq!(
+            Vector4::from((0.0, 0.0, 0.0, 1.0)).lerp(&(4.0, 4.0, 4.0, 1.0).into(), 0.75),
+            (3.0, 3.0, 3.0, 1.0).into()
+        );
+
+        let black = Color::default();
+        let white = Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+
+        assert_eq!(
+            black.lerp(&white, 0.5),
+            Color {
+                r: 0.5,
+                g: 0.5,
+                b: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn test_interpolate_barycentric() {
+        let (a, b, c) = (1.0, 2.0, 4.0);
+
+        assert_eq!(
+            interpolate_barycentric(&a, &b, &c, &(1.0, 0.0, 0.0).into()),
+            a
+        );
+        assert_eq!(
+            interpolate_barycentric(&a, &b, &c, &(0.0, 1.0, 0.0).into()),
+            b
+        );
+        assert_eq!(
+            interpolate_barycentric(&a, &b, &c, &(0.0, 0.0, 1.0).into()),
+            c
+        );
+        assert_eq!(
+            interpolate_barycentric(&a, &b, &c, &(0.5, 0.25, 0.25).into()),
+            0.5 * a + 0.25 * b + 0.25 * c
+        );
+
+        let uv = interpolate_barycentric(
+            &Vector2::from((0.0, 0.0)),
+            &(1.0, 0.0).into(),
+            &(0.0, 1.0).into(),
+            &(0.2, 0.3, 0.5).into(),
+        );
+
+        assert!((uv.x - 0.3).abs() < 1e-6 && (uv.y - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_interpolate_barycentric_outside() {
+        let (a, b, c) = (1.0, 2.0, 4.0);
+
+        // Points outside the triangle have negative coordinates, and u + v
+        // may be zero or close to it.
+        assert_eq!(
+            interpolate_barycentric(&a, &b, &c, &(0.5, -0.5, 1.0).into()),
+            3.5
+        );
+
+        let value = interpolate_barycentric(&a, &b, &c, &(0.3, -0.2999999, 0.9999999).into());
+
+        assert!((value - 3.7).abs() < 1e-5);
+
+        let color = interpolate_barycentric(
+            &Color::default(),
+            &Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+            },
+            &Color {
+                r: 0.0,
+                g: 0.0,
+                b: 1.0,
+            },
+            &(1.5, -0.25, -0.25).into(),
+        );
+
+        assert_eq!(
+            color,
+            Color {
+                r: -0.25,
+                g: 0.0,
+                b: -0.25
+            }
+        );
+    }
+}
@@ -6,20 +6,71 @@
 //! This library uses C-style layout for types. This allows quantites such as
 //! matrices and vectors to be written to GPU buffers and passed to shaders
 //! correctly.
+//!
+//! ## Feature flags
+//!
+//! - `fma`: Uses fused multiply-add in dot products and matrix
+//!   multiplication. This is more precise, and faster on targets with
+//!   hardware FMA, but much slower on targets without it.
+//! - `testing`: Enables the `testing` module with assertions for invariants
+//!   of rotations and transformations.
+//! - `wasm`: Adds `to_js_array` methods that convert matrices and quaternions
+//!   to `f64` arrays, which can be passed to JavaScript as a `Float64Array`
+//!   without per-element conversion glue.
 
 #[macro_use]
 extern crate impl_ops;
 
+mod alignment;
+mod axis_angle;
+mod basis;
+mod box2;
+mod bytes;
 mod color;
+mod error;
 mod euler;
+mod field;
+mod format;
+mod gradient;
+mod joint_palette;
+mod lerp;
+mod linalg;
 mod matrix3;
 mod matrix4;
+mod mesh;
+mod orientation_filter;
+mod quantization;
 mod quaternion;
+mod ray_cone;
+mod spring;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tetrahedron;
+mod vector2;
 mod vector3;
+mod vector4;
 
+pub use alignment::*;
+pub use axis_angle::*;
+pub use basis::*;
+pub use box2::*;
+pub use bytes::*;
 pub use color::*;
+pub use error::*;
 pub use euler::*;
+pub use field::*;
+pub use gradient::*;
+pub use joint_palette::*;
+pub use lerp::*;
 pub use matrix3::*;
 pub use matrix4::*;
+pub use mesh::*;
+pub use orientation_filter::*;
+pub use quantization::*;
 pub use quaternion::*;
+pub use ray_cone::*;
+pub use spring::*;
+pub use tetrahedron::*;
+pub use vector2::*;
 pub use vector3::*;
+pub use vector4::*;
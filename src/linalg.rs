@@ -0,0 +1,155 @@
+//! Internal linear algebra routines shared by several types.
+
+use std::f32::consts::{PI, TAU};
+
+/// Returns the sum of the products of the matching elements of `a` and `b`,
+/// i.e. their dot product.
+///
+/// With the `fma` feature, each product is fused with the running sum using
+/// [`f32::mul_add`], which rounds once instead of twice.
+#[inline]
+pub(crate) fn sum_of_products<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    a.iter().zip(&b).skip(1).fold(a[0] * b[0], |sum, (x, y)| {
+        if cfg!(feature = "fma") {
+            x.mul_add(*y, sum)
+        } else {
+            sum + x * y
+        }
+    })
+}
+
+/// Wraps the given angle in radians to be between `-π` and `π`.
+pub(crate) fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+
+    if wrapped == -PI {
+        PI
+    } else {
+        wrapped
+    }
+}
+
+/// Maximum number of Jacobi sweeps before giving up on convergence.
+const MAX_SWEEPS: usize = 64;
+
+/// Computes the eigenvalues and eigenvectors of the given symmetric matrix
+/// using the cyclic Jacobi eigenvalue algorithm.
+///
+/// `a[row][column]` is the element at the given row and column. Only
+/// symmetric matrices are supported; the lower triangle is assumed to mirror
+/// the upper triangle.
+///
+/// Returns the eigenvalues and the unit eigenvectors, where the `i`-th
+/// eigenvector corresponds to the `i`-th eigenvalue. The eigenvalues are not
+/// sorted.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn symmetric_eigen<const N: usize>(mut a: [[f64; N]; N]) -> ([f64; N], [[f64; N]; N]) {
+    let mut v = [[0.0; N]; N];
+
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off_diagonal = 0.0;
+
+        for p in 0..N {
+            for q in p + 1..N {
+                off_diagonal += a[p][q] * a[p][q];
+            }
+        }
+
+        if off_diagonal < f64::MIN_POSITIVE {
+            break;
+        }
+
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let akp = row[p];
+                    let akq = row[q];
+
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+
+                for k in 0..N {
+                    let apk = a[p][k];
+                    let aqk = a[q][k];
+
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+
+                for row in v.iter_mut() {
+                    let vkp = row[p];
+                    let vkq = row[q];
+
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let mut values = [0.0; N];
+    let mut vectors = [[0.0; N]; N];
+
+    for i in 0..N {
+        values[i] = a[i][i];
+
+        for k in 0..N {
+            vectors[i][k] = v[k][i];
+        }
+    }
+
+    (values, vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_wrap_angle() {
+        assert_float_absolute_eq!(wrap_angle(0.5), 0.5);
+        assert_float_absolute_eq!(wrap_angle(-PI), PI);
+        assert_float_absolute_eq!(wrap_angle(PI), PI);
+        assert_float_absolute_eq!(wrap_angle(TAU + 0.5), 0.5, 1e-5);
+        assert_float_absolute_eq!(wrap_angle(-TAU - 0.5), -0.5, 1e-5);
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let a = [[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]];
+
+        let (values, vectors) = symmetric_eigen(a);
+
+        for i in 0..3 {
+            // A * v = λ * v
+            for row in 0..3 {
+                let av: f64 = (0..3).map(|k| a[row][k] * vectors[i][k]).sum();
+
+                assert_float_absolute_eq!(av, values[i] * vectors[i][row], 1e-9);
+            }
+        }
+
+        let mut sorted = values;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_float_absolute_eq!(sorted[0], 1.0, 1e-9);
+        assert_float_absolute_eq!(sorted[1], 3.0, 1e-9);
+        assert_float_absolute_eq!(sorted[2], 5.0, 1e-9);
+    }
+}
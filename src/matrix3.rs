@@ -1,10 +1,27 @@
-use std::ops;
+use std::{fmt, ops, str::FromStr};
 
+use crate::format::{parse_rows, write_rows};
+use crate::linalg::{sum_of_products, symmetric_eigen};
 use crate::Vector3;
 
-use super::Matrix4;
+use super::{Matrix4, ParseError};
 
 /// 3x3 matrix.
+///
+/// ## Supported operators
+///
+/// - [`ops::Mul`], [`ops::MulAssign`]
+///   - Matrix multiplication
+///   - Matrix-vector multiplication (without [`ops::MulAssign`])
+///   - Element-wise multiplication by a scalar (commutative)
+/// - [`ops::Div`], [`ops::DivAssign`]
+///   - Element-wise division by a scalar
+///   - Element-wise division of a scalar by a matrix, e.g. `1.0 / m` for
+///     the reciprocal of each element (without [`ops::DivAssign`])
+/// - [`ops::Add`], [`ops::AddAssign`]
+///   - Element-wise matrix addition
+/// - [`ops::Index`], [`ops::IndexMut`]
+///   - Access to the columns by index, e.g. `m[0]` for the first column
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Matrix3 {
@@ -23,6 +40,33 @@ impl Default for Matrix3 {
 
 impl Eq for Matrix3 {}
 
+impl fmt::Display for Matrix3 {
+    /// Formats this matrix row by row, e.g. `((1, 0, 0), (0, 1, 0), (0, 0, 1))`
+    /// for the 3x3 identity matrix.
+    ///
+    /// The precision, if any, applies to each element, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: [[f32; 3]; 3] =
+            std::array::from_fn(|i| std::array::from_fn(|j| self.elements[j * 3 + i]));
+
+        write_rows(f, &rows)
+    }
+}
+
+impl FromStr for Matrix3 {
+    type Err = ParseError;
+
+    /// Parses a matrix in the format written by `Display`, i.e. row by row.
+    /// The outer parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let rows = parse_rows::<f32, 3, 3>(s)?;
+
+        Ok(Self {
+            elements: std::array::from_fn(|k| rows[k % 3][k / 3]),
+        })
+    }
+}
+
 impl_op_ex!(*|a: &Matrix3, b: &Vector3| -> Vector3 {
     let a11 = a.elements[0];
     let a21 = a.elements[1];
@@ -35,19 +79,133 @@ impl_op_ex!(*|a: &Matrix3, b: &Vector3| -> Vector3 {
     let a33 = a.elements[8];
 
     Vector3 {
-        x: a11 * b.x + a12 * b.y + a13 * b.z,
-        y: a21 * b.x + a22 * b.y + a23 * b.z,
-        z: a31 * b.x + a32 * b.y + a33 * b.z,
+        x: sum_of_products([a11, a12, a13], [b.x, b.y, b.z]),
+        y: sum_of_products([a21, a22, a23], [b.x, b.y, b.z]),
+        z: sum_of_products([a31, a32, a33], [b.x, b.y, b.z]),
     }
 });
 
-impl_op_ex_commutative!(/|a: &Matrix3, b: &f32| -> Matrix3 {
+impl_op_ex!(*|a: &Matrix3, b: &Matrix3| -> Matrix3 {
+    let a11 = a.elements[0];
+    let a21 = a.elements[1];
+    let a31 = a.elements[2];
+    let a12 = a.elements[3];
+    let a22 = a.elements[4];
+    let a32 = a.elements[5];
+    let a13 = a.elements[6];
+    let a23 = a.elements[7];
+    let a33 = a.elements[8];
+
+    let b11 = b.elements[0];
+    let b21 = b.elements[1];
+    let b31 = b.elements[2];
+    let b12 = b.elements[3];
+    let b22 = b.elements[4];
+    let b32 = b.elements[5];
+    let b13 = b.elements[6];
+    let b23 = b.elements[7];
+    let b33 = b.elements[8];
+
+    Matrix3::new(
+        sum_of_products([a11, a12, a13], [b11, b21, b31]),
+        sum_of_products([a11, a12, a13], [b12, b22, b32]),
+        sum_of_products([a11, a12, a13], [b13, b23, b33]),
+        sum_of_products([a21, a22, a23], [b11, b21, b31]),
+        sum_of_products([a21, a22, a23], [b12, b22, b32]),
+        sum_of_products([a21, a22, a23], [b13, b23, b33]),
+        sum_of_products([a31, a32, a33], [b11, b21, b31]),
+        sum_of_products([a31, a32, a33], [b12, b22, b32]),
+        sum_of_products([a31, a32, a33], [b13, b23, b33]),
+    )
+});
+
+impl_op_ex!(*= |a: &mut Matrix3, b: &Matrix3| {
+    *a = *a * b;
+});
+
+impl_op_ex_commutative!(*|a: &Matrix3, b: &f32| -> Matrix3 {
+    Matrix3 {
+        elements: a.elements.map(|x| x * b),
+    }
+});
+
+impl_op_ex!(*= |a: &mut Matrix3, b: &f32| {
+    a.elements.iter_mut().for_each(|x| {
+        *x *= b;
+    });
+});
+
+impl_op_ex!(/|a: &Matrix3, b: &f32| -> Matrix3 {
     Matrix3 {
         elements: a.elements.map(|x| x / b),
     }
 });
 
+impl_op_ex!(/|a: &f32, b: &Matrix3| -> Matrix3 {
+    Matrix3 {
+        elements: b.elements.map(|x| a / x),
+    }
+});
+
+impl_op_ex!(/= |a: &mut Matrix3, b: &f32| {
+    a.elements.iter_mut().for_each(|x| {
+        *x /= b;
+    });
+});
+
+impl_op_ex!(+|a: &Matrix3, b: &Matrix3| -> Matrix3 {
+    let mut elements = a.elements;
+
+    elements
+        .iter_mut()
+        .zip(b.elements)
+        .for_each(|(x, y)| *x += y);
+
+    Matrix3 { elements }
+});
+
+impl_op_ex!(+= |a: &mut Matrix3, b: &Matrix3| {
+    a.elements
+        .iter_mut()
+        .zip(b.elements)
+        .for_each(|(x, y)| *x += y);
+});
+
+impl ops::Index<usize> for Matrix3 {
+    type Output = Vector3;
+
+    /// Returns the column at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 2.
+    fn index(&self, index: usize) -> &Vector3 {
+        &bytemuck::cast_slice::<f32, Vector3>(&self.elements)[index]
+    }
+}
+
+impl ops::IndexMut<usize> for Matrix3 {
+    /// Returns the column at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 2.
+    fn index_mut(&mut self, index: usize) -> &mut Vector3 {
+        &mut bytemuck::cast_slice_mut::<f32, Vector3>(&mut self.elements)[index]
+    }
+}
+
 impl Matrix3 {
+    /// The 3x3 identity matrix.
+    #[rustfmt::skip]
+    pub const IDENTITY: Self = Self {
+        elements: [
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0
+        ],
+    };
+
     /// Creates a new 3x3 matrix with the given row-major elements. The elements
     /// will be stored internally in column-major order.
     #[rustfmt::skip]
@@ -68,14 +226,7 @@ impl Matrix3 {
 
     /// Returns the 3x3 identity matrix.
     pub fn identity() -> Self {
-        Matrix3 {
-            #[rustfmt::skip]
-            elements: [
-                1.0, 0.0, 0.0,
-                0.0, 1.0, 0.0,
-                0.0, 0.0, 1.0
-            ],
-        }
+        Self::IDENTITY
     }
 
     /// Returns the 3x3 zero matrix.
@@ -100,6 +251,26 @@ impl Matrix3 {
         }
     }
 
+    /// Returns the rotation matrix for the given rotation vector, whose
+    /// direction is the rotation axis and whose length is the rotation angle
+    /// in radians. This is the axis-angle form commonly used in computer
+    /// vision, e.g. by OpenCV.
+    ///
+    /// The matrix is computed with [Rodrigues' rotation formula][rodrigues].
+    ///
+    /// [rodrigues]: https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula
+    pub fn from_rodrigues(rotation: &Vector3) -> Self {
+        let angle = rotation.length();
+
+        if angle == 0.0 {
+            return Self::identity();
+        }
+
+        let k = (rotation / angle).cross_matrix();
+
+        Self::identity() + k * angle.sin() + k * k * (1.0 - angle.cos())
+    }
+
     /// Sets the elements of this matrix with the given row-major elements.
     #[rustfmt::skip]
     #[allow(clippy::too_many_arguments)]
@@ -120,6 +291,39 @@ impl Matrix3 {
         self.elements[8] = n33;
     }
 
+    /// Returns a matrix with `f` applied to each element of this matrix.
+    pub fn map(&self, f: impl FnMut(f32) -> f32) -> Self {
+        Self {
+            elements: self.elements.map(f),
+        }
+    }
+
+    /// Returns a matrix with `f` applied to each pair of corresponding
+    /// elements of this matrix and `other`.
+    pub fn zip_with(&self, other: &Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Self {
+            elements: std::array::from_fn(|i| f(self.elements[i], other.elements[i])),
+        }
+    }
+
+    /// Returns an iterator over the columns of this matrix, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = Vector3> + '_ {
+        self.elements.chunks_exact(3).map(|column| Vector3 {
+            x: column[0],
+            y: column[1],
+            z: column[2],
+        })
+    }
+
+    /// Returns an iterator over the rows of this matrix, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = Vector3> + '_ {
+        (0..3).map(move |i| Vector3 {
+            x: self.elements[i],
+            y: self.elements[i + 3],
+            z: self.elements[i + 6],
+        })
+    }
+
     /// Returns the normal matrix for the given transformation matrix, which is
     /// multiplied with normal vectors to correct for deforms such as scaling
     /// and skewing.
@@ -149,6 +353,44 @@ impl Matrix3 {
             - n13 * n22 * n31
     }
 
+    /// Returns the trace of this matrix, i.e. the sum of its diagonal elements.
+    pub fn trace(&self) -> f32 {
+        self.elements[0] + self.elements[4] + self.elements[8]
+    }
+
+    /// Returns the eigenvalues and eigenvectors of this matrix, which must be
+    /// symmetric, e.g. a covariance matrix or an inertia tensor.
+    ///
+    /// The eigenvalues are sorted in descending order. The columns of the
+    /// returned matrix are the corresponding unit eigenvectors, so the matrix
+    /// is a rotation (or a reflection) into the principal axes.
+    ///
+    /// The elements above the diagonal are used and the ones below are
+    /// assumed to mirror them.
+    pub fn eigen_symmetric(&self) -> ([f32; 3], Matrix3) {
+        let e = self.elements.map(|x| x as f64);
+
+        #[rustfmt::skip]
+        let (values, vectors) = symmetric_eigen([
+            [e[0], e[3], e[6]],
+            [e[3], e[4], e[7]],
+            [e[6], e[7], e[8]],
+        ]);
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| values[j].total_cmp(&values[i]));
+
+        let mut elements = [0.0; 9];
+
+        for (column, &i) in order.iter().enumerate() {
+            for k in 0..3 {
+                elements[column * 3 + k] = vectors[i][k] as f32;
+            }
+        }
+
+        (order.map(|i| values[i] as f32), Matrix3 { elements })
+    }
+
     /// Returns the transpose of this matrix.
     pub fn transpose(&self) -> Self {
         Self {
@@ -166,6 +408,20 @@ impl Matrix3 {
         }
     }
 
+    /// Transposes this matrix in place.
+    pub fn transpose_in_place(&mut self) {
+        self.elements.swap(1, 3);
+        self.elements.swap(2, 6);
+        self.elements.swap(5, 7);
+    }
+
+    /// Returns the elements of this matrix in column-major order as `f64`s,
+    /// which is the layout of a JavaScript `Float64Array`.
+    #[cfg(feature = "wasm")]
+    pub fn to_js_array(&self) -> [f64; 9] {
+        self.elements.map(f64::from)
+    }
+
     /// Returns the adjugate of this matrix, also known as the classical adjoint
     /// or the adjunct.
     pub fn adjugate(&self) -> Self {
@@ -207,6 +463,46 @@ impl Matrix3 {
             Self::zero()
         }
     }
+
+    /// Returns the transpose of the inverse of this matrix. If this matrix has
+    /// no inverse i.e. the determinant is zero, then return the 3x3 zero
+    /// matrix.
+    ///
+    /// This is the classical normal matrix. Unlike calling [`Self::inverse`]
+    /// and then [`Self::transpose`], no intermediate matrix is built.
+    pub fn inverse_transpose(&self) -> Self {
+        let n11 = self.elements[0];
+        let n21 = self.elements[1];
+        let n31 = self.elements[2];
+        let n12 = self.elements[3];
+        let n22 = self.elements[4];
+        let n32 = self.elements[5];
+        let n13 = self.elements[6];
+        let n23 = self.elements[7];
+        let n33 = self.elements[8];
+
+        let det = self.determinant();
+
+        if det == 0.0 {
+            return Self::zero();
+        }
+
+        let inv_det = 1.0 / det;
+
+        Self {
+            elements: [
+                (n22 * n33 - n23 * n32) * inv_det,
+                (n13 * n32 - n12 * n33) * inv_det,
+                (n12 * n23 - n13 * n22) * inv_det,
+                (n23 * n31 - n21 * n33) * inv_det,
+                (n11 * n33 - n13 * n31) * inv_det,
+                (n13 * n21 - n11 * n23) * inv_det,
+                (n21 * n32 - n22 * n31) * inv_det,
+                (n12 * n31 - n11 * n32) * inv_det,
+                (n11 * n22 - n12 * n21) * inv_det,
+            ],
+        }
+    }
 }
 
 #[cfg(test)]
@@ -280,6 +576,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_rodrigues() {
+        assert_eq!(
+            Matrix3::from_rodrigues(&Vector3::default()),
+            Matrix3::identity()
+        );
+
+        let rotation = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: std::f32::consts::FRAC_PI_2,
+        };
+
+        let actual = Matrix3::from_rodrigues(&rotation);
+
+        #[rustfmt::skip]
+        let expected = Matrix3::new(
+            0.0, -1.0, 0.0,
+            1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0
+        );
+
+        for i in 0..9 {
+            assert_float_absolute_eq!(actual.elements[i], expected.elements[i]);
+        }
+
+        let rotation = Vector3 {
+            x: 0.3,
+            y: -0.2,
+            z: 0.5,
+        };
+        let v = Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+
+        let actual = Matrix3::from_rodrigues(&rotation) * v;
+        let expected = v.rotate_about_axis(&rotation.normalized(), rotation.length());
+
+        assert_float_absolute_eq!(actual.x, expected.x);
+        assert_float_absolute_eq!(actual.y, expected.y);
+        assert_float_absolute_eq!(actual.z, expected.z);
+    }
+
     #[test]
     fn test_set() {
         #[rustfmt::skip]
@@ -301,6 +642,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_index() {
+        #[rustfmt::skip]
+        let mut m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        );
+
+        assert_eq!(m[0], (1.0, 4.0, 7.0).into());
+        assert_eq!(m[2], (3.0, 6.0, 9.0).into());
+
+        m[1] = (0.0, 0.0, 0.0).into();
+        m[2].y = 10.0;
+
+        assert_eq!(m.elements, [1.0, 4.0, 7.0, 0.0, 0.0, 0.0, 3.0, 10.0, 9.0]);
+    }
+
+    #[test]
+    fn test_columns_and_rows() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        );
+
+        let columns: Vec<Vector3> = m.columns().collect();
+        let rows: Vec<Vector3> = m.rows().collect();
+
+        assert_eq!(
+            columns,
+            [
+                (1.0, 4.0, 7.0).into(),
+                (2.0, 5.0, 8.0).into(),
+                (3.0, 6.0, 9.0).into()
+            ]
+        );
+        assert_eq!(
+            rows,
+            [
+                (1.0, 2.0, 3.0).into(),
+                (4.0, 5.0, 6.0).into(),
+                (7.0, 8.0, 9.0).into()
+            ]
+        );
+    }
+
     #[test]
     fn test_normal() {
         #[rustfmt::skip]
@@ -345,6 +734,44 @@ mod tests {
         assert_eq!(m.determinant(), -73.0);
     }
 
+    #[test]
+    fn test_trace() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        );
+
+        assert_eq!(m.trace(), 15.0);
+        assert_eq!(Matrix3::identity().trace(), 3.0);
+    }
+
+    #[test]
+    fn test_eigen_symmetric() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            4.0, 1.0, 2.0,
+            1.0, 3.0, 0.0,
+            2.0, 0.0, 5.0
+        );
+
+        let (values, vectors) = m.eigen_symmetric();
+
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+        assert_float_absolute_eq!(values.iter().sum::<f32>(), m.trace(), 1e-5);
+
+        for (value, vector) in values.iter().zip(vectors.columns()) {
+            let expected = vector * *value;
+            let actual = m * vector;
+
+            assert_float_absolute_eq!(vector.length(), 1.0, 1e-5);
+            assert_float_absolute_eq!(actual.x, expected.x, 1e-5);
+            assert_float_absolute_eq!(actual.y, expected.y, 1e-5);
+            assert_float_absolute_eq!(actual.z, expected.z, 1e-5);
+        }
+    }
+
     #[test]
     fn test_transpose() {
         #[rustfmt::skip]
@@ -362,6 +789,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transpose_in_place() {
+        #[rustfmt::skip]
+        let mut m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        );
+
+        let expected = m.transpose();
+        m.transpose_in_place();
+
+        assert_eq!(m, expected);
+    }
+
     #[test]
     fn test_adjugate() {
         #[rustfmt::skip]
@@ -391,6 +833,70 @@ mod tests {
         assert_float_absolute_eq!(actual.elements[8], expected.elements[8]);
     }
 
+    #[test]
+    fn test_matrix_multiplication() {
+        #[rustfmt::skip]
+        let mut a = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        );
+
+        #[rustfmt::skip]
+        let b = Matrix3::new(
+            10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0,
+            16.0, 17.0, 18.0,
+        );
+
+        #[rustfmt::skip]
+        let expected = Matrix3::new(
+            84.0, 90.0, 96.0,
+            201.0, 216.0, 231.0,
+            318.0, 342.0, 366.0,
+        );
+
+        assert_eq!(a * b, expected);
+
+        a *= b;
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn test_matrix_scalar_operations() {
+        #[rustfmt::skip]
+        let mut m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0,
+        );
+
+        for i in 0..9 {
+            assert_eq!((m * 2.0).elements[i], ((cm_to_rm(i) + 1) * 2) as f32);
+            assert_eq!((2.0 * m).elements[i], ((cm_to_rm(i) + 1) * 2) as f32);
+            assert_eq!((m / 2.0).elements[i], (cm_to_rm(i) + 1) as f32 / 2.0);
+            assert_eq!((2.0 / m).elements[i], 2.0 / (cm_to_rm(i) + 1) as f32);
+        }
+
+        m *= 4.0;
+        m /= 2.0;
+
+        for i in 0..9 {
+            assert_eq!(m.elements[i], ((cm_to_rm(i) + 1) * 2) as f32);
+        }
+    }
+
+    #[test]
+    fn test_matrix_addition() {
+        let mut m = Matrix3::identity();
+
+        assert_eq!(m + Matrix3::zero(), m);
+        assert_eq!(m + m, m * 2.0);
+
+        m += Matrix3::identity();
+        assert_eq!(m, Matrix3::identity() * 2.0);
+    }
+
     #[test]
     fn test_inverse() {
         #[rustfmt::skip]
@@ -418,4 +924,67 @@ mod tests {
 
         assert_eq!(degenerate.inverse(), Matrix3::zero());
     }
+
+    #[test]
+    fn test_inverse_transpose() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            2.0, 3.0, 4.0,
+            5.0, 13.0, 7.0,
+            8.0, 9.0, 11.0
+        );
+
+        let actual = m.inverse_transpose();
+        let expected = m.inverse().transpose();
+
+        for i in 0..9 {
+            assert_float_absolute_eq!(actual.elements[i], expected.elements[i]);
+        }
+
+        assert_eq!(Matrix3::zero().inverse_transpose(), Matrix3::zero());
+    }
+
+    #[test]
+    fn test_map_and_zip_with() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            1.0, -2.0, 3.0,
+            -4.0, 5.0, -6.0,
+            7.0, -8.0, 9.0,
+        );
+
+        let abs = m.map(f32::abs);
+        let sum = m.zip_with(&abs, |a, b| a + b);
+
+        for i in 0..9 {
+            assert_eq!(abs.elements[i], m.elements[i].abs());
+            assert_eq!(sum.elements[i], m.elements[i] + abs.elements[i]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "wasm")]
+    fn test_to_js_array() {
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.5,
+        );
+
+        assert_eq!(
+            m.to_js_array(),
+            [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.5]
+        );
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.5);
+
+        assert_eq!(m.to_string(), "((1, 2, 3), (4, 5, 6), (7, 8, 9.5))");
+        assert_eq!(m.to_string().parse(), Ok(m));
+        assert_eq!("(1, 2, 3), (4, 5, 6), (7, 8, 9.5)".parse(), Ok(m));
+        assert!("((1, 2, 3), (4, 5, 6))".parse::<Matrix3>().is_err());
+    }
 }
@@ -1,8 +1,11 @@
-use std::ops;
+use std::{fmt, ops, str::FromStr};
 
 use impl_ops::impl_op_ex;
 
-use super::{Euler, Quaternion, Vector3};
+use crate::format::{parse_rows, write_rows};
+use crate::linalg::sum_of_products;
+
+use super::{Euler, Matrix3, ParseError, Quaternion, Vector3, Vector4};
 
 /// 4x4 matrix, commonly used to encode transformations i.e. translation,
 /// rotation, and scale.
@@ -13,7 +16,11 @@ use super::{Euler, Quaternion, Vector3};
 ///   - Matrix multiplication
 ///   - Element-wise multiplication by a scalar (commutative)
 /// - [`ops::Div`], [`ops::DivAssign`]
-///   - Element-wise division by a scalar (commutative)
+///   - Element-wise division by a scalar
+/// - [`ops::Add`], [`ops::AddAssign`]
+///   - Element-wise matrix addition
+/// - [`ops::Index`], [`ops::IndexMut`]
+///   - Access to the columns by index, e.g. `m[0]` for the first column
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Matrix4 {
@@ -31,6 +38,33 @@ impl Default for Matrix4 {
     }
 }
 
+impl fmt::Display for Matrix4 {
+    /// Formats this matrix row by row, e.g. `((1, 0, 0, 0), (0, 1, 0, 0), ...)`
+    /// for the 4x4 identity matrix.
+    ///
+    /// The precision, if any, applies to each element, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: [[f32; 4]; 4] =
+            std::array::from_fn(|i| std::array::from_fn(|j| self.elements[j * 4 + i]));
+
+        write_rows(f, &rows)
+    }
+}
+
+impl FromStr for Matrix4 {
+    type Err = ParseError;
+
+    /// Parses a matrix in the format written by `Display`, i.e. row by row.
+    /// The outer parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let rows = parse_rows::<f32, 4, 4>(s)?;
+
+        Ok(Self {
+            elements: std::array::from_fn(|k| rows[k % 4][k / 4]),
+        })
+    }
+}
+
 impl AsRef<Matrix4> for Matrix4 {
     fn as_ref(&self) -> &Matrix4 {
         self
@@ -73,22 +107,22 @@ impl_op_ex!(*|a: &Matrix4, b: &Matrix4| -> Matrix4 {
     let b44 = b.elements[15];
 
     Matrix4::new(
-        a11 * b11 + a12 * b21 + a13 * b31 + a14 * b41,
-        a11 * b12 + a12 * b22 + a13 * b32 + a14 * b42,
-        a11 * b13 + a12 * b23 + a13 * b33 + a14 * b43,
-        a11 * b14 + a12 * b24 + a13 * b34 + a14 * b44,
-        a21 * b11 + a22 * b21 + a23 * b31 + a24 * b41,
-        a21 * b12 + a22 * b22 + a23 * b32 + a24 * b42,
-        a21 * b13 + a22 * b23 + a23 * b33 + a24 * b43,
-        a21 * b14 + a22 * b24 + a23 * b34 + a24 * b44,
-        a31 * b11 + a32 * b21 + a33 * b31 + a34 * b41,
-        a31 * b12 + a32 * b22 + a33 * b32 + a34 * b42,
-        a31 * b13 + a32 * b23 + a33 * b33 + a34 * b43,
-        a31 * b14 + a32 * b24 + a33 * b34 + a34 * b44,
-        a41 * b11 + a42 * b21 + a43 * b31 + a44 * b41,
-        a41 * b12 + a42 * b22 + a43 * b32 + a44 * b42,
-        a41 * b13 + a42 * b23 + a43 * b33 + a44 * b43,
-        a41 * b14 + a42 * b24 + a43 * b34 + a44 * b44,
+        sum_of_products([a11, a12, a13, a14], [b11, b21, b31, b41]),
+        sum_of_products([a11, a12, a13, a14], [b12, b22, b32, b42]),
+        sum_of_products([a11, a12, a13, a14], [b13, b23, b33, b43]),
+        sum_of_products([a11, a12, a13, a14], [b14, b24, b34, b44]),
+        sum_of_products([a21, a22, a23, a24], [b11, b21, b31, b41]),
+        sum_of_products([a21, a22, a23, a24], [b12, b22, b32, b42]),
+        sum_of_products([a21, a22, a23, a24], [b13, b23, b33, b43]),
+        sum_of_products([a21, a22, a23, a24], [b14, b24, b34, b44]),
+        sum_of_products([a31, a32, a33, a34], [b11, b21, b31, b41]),
+        sum_of_products([a31, a32, a33, a34], [b12, b22, b32, b42]),
+        sum_of_products([a31, a32, a33, a34], [b13, b23, b33, b43]),
+        sum_of_products([a31, a32, a33, a34], [b14, b24, b34, b44]),
+        sum_of_products([a41, a42, a43, a44], [b11, b21, b31, b41]),
+        sum_of_products([a41, a42, a43, a44], [b12, b22, b32, b42]),
+        sum_of_products([a41, a42, a43, a44], [b13, b23, b33, b43]),
+        sum_of_products([a41, a42, a43, a44], [b14, b24, b34, b44]),
     )
 });
 
@@ -120,7 +154,60 @@ impl_op_ex!(/= |a: &mut Matrix4, b: &f32| {
     });
 });
 
+impl_op_ex!(+|a: &Matrix4, b: &Matrix4| -> Matrix4 {
+    let mut elements = a.elements;
+
+    elements
+        .iter_mut()
+        .zip(b.elements)
+        .for_each(|(x, y)| *x += y);
+
+    Matrix4 { elements }
+});
+
+impl_op_ex!(+= |a: &mut Matrix4, b: &Matrix4| {
+    a.elements
+        .iter_mut()
+        .zip(b.elements)
+        .for_each(|(x, y)| *x += y);
+});
+
+impl ops::Index<usize> for Matrix4 {
+    type Output = Vector4;
+
+    /// Returns the column at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 3.
+    fn index(&self, index: usize) -> &Vector4 {
+        &bytemuck::cast_slice::<f32, Vector4>(&self.elements)[index]
+    }
+}
+
+impl ops::IndexMut<usize> for Matrix4 {
+    /// Returns the column at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 3.
+    fn index_mut(&mut self, index: usize) -> &mut Vector4 {
+        &mut bytemuck::cast_slice_mut::<f32, Vector4>(&mut self.elements)[index]
+    }
+}
+
 impl Matrix4 {
+    /// The 4x4 identity matrix.
+    #[rustfmt::skip]
+    pub const IDENTITY: Self = Self {
+        elements: [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ],
+    };
+
     /// Creates a new 4x4 matrix with the given row-major elements. The elements
     /// will be stored internally in column-major order.
     #[rustfmt::skip]
@@ -143,15 +230,7 @@ impl Matrix4 {
 
     /// Returns the 4x4 identity matrix.
     pub fn identity() -> Self {
-        Self {
-            #[rustfmt::skip]
-            elements: [
-                1.0, 0.0, 0.0, 0.0,
-                0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 1.0, 0.0,
-                0.0, 0.0, 0.0, 1.0,
-            ],
-        }
+        Self::IDENTITY
     }
 
     /// Returns the 4x4 zero matrix.
@@ -424,6 +503,25 @@ impl Matrix4 {
         Self { elements }
     }
 
+    /// Creates a matrix for the transformation composed of the given
+    /// translation, rotation, and scale, where the rotation and scale are
+    /// applied around the given pivot point instead of the origin.
+    ///
+    /// This is equivalent to translating by `-pivot`, then scaling, rotating,
+    /// translating back by `pivot`, and finally translating by `translation`.
+    /// The pivot point is therefore only moved by `translation`.
+    pub fn compose_with_pivot(
+        translation: &Vector3,
+        rotation: &Quaternion,
+        scale: &Vector3,
+        pivot: &Vector3,
+    ) -> Self {
+        let mut m = Self::compose(&(translation + pivot), rotation, scale);
+        m.translate(&-pivot);
+
+        m
+    }
+
     /// Returns a rotation matrix looking from `eye` towards `target` oriented
     /// by the `up` vector.
     pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Self {
@@ -481,6 +579,41 @@ impl Matrix4 {
         self.elements[15] = n44;
     }
 
+    /// Returns a matrix with `f` applied to each element of this matrix.
+    pub fn map(&self, f: impl FnMut(f32) -> f32) -> Self {
+        Self {
+            elements: self.elements.map(f),
+        }
+    }
+
+    /// Returns a matrix with `f` applied to each pair of corresponding
+    /// elements of this matrix and `other`.
+    pub fn zip_with(&self, other: &Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Self {
+            elements: std::array::from_fn(|i| f(self.elements[i], other.elements[i])),
+        }
+    }
+
+    /// Returns an iterator over the columns of this matrix, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = Vector4> + '_ {
+        self.elements.chunks_exact(4).map(|column| Vector4 {
+            x: column[0],
+            y: column[1],
+            z: column[2],
+            w: column[3],
+        })
+    }
+
+    /// Returns an iterator over the rows of this matrix, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = Vector4> + '_ {
+        (0..4).map(move |i| Vector4 {
+            x: self.elements[i],
+            y: self.elements[i + 4],
+            z: self.elements[i + 8],
+            w: self.elements[i + 12],
+        })
+    }
+
     /// Returns the translation component of this matrix.
     pub fn translation(&self) -> Vector3 {
         Vector3 {
@@ -621,8 +754,177 @@ impl Matrix4 {
             self.adjugate() / det
         }
     }
+
+    /// Returns the elements of this matrix in column-major order as `f64`s,
+    /// which is the layout of a JavaScript `Float64Array` and of matrices in
+    /// most JavaScript libraries.
+    #[cfg(feature = "wasm")]
+    pub fn to_js_array(&self) -> [f64; 16] {
+        self.elements.map(f64::from)
+    }
+
+    /// Returns a hash of the elements of this matrix that is stable across
+    /// runs, platforms, and compiler versions, e.g. to key render caches or to
+    /// skip redundant GPU uploads.
+    ///
+    /// The hash is computed with 64-bit FNV-1a over the bit patterns of the
+    /// elements. `-0.0` and `0.0` are normalized to the same bit pattern, as
+    /// are all NaN payloads, so matrices that compare equal element-wise have
+    /// equal hashes and matrices containing NaNs hash consistently.
+    pub fn content_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const PRIME: u64 = 0x100000001b3;
+
+        self.elements
+            .iter()
+            .flat_map(|&x| {
+                let bits = if x == 0.0 {
+                    0
+                } else if x.is_nan() {
+                    f32::NAN.to_bits()
+                } else {
+                    x.to_bits()
+                };
+
+                bits.to_le_bytes()
+            })
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(PRIME)
+            })
+    }
+
+    /// Returns whether this matrix is an affine transformation, i.e. whether
+    /// its bottom row is exactly `(0, 0, 0, 1)`.
+    pub fn is_affine(&self) -> bool {
+        self.elements[3] == 0.0
+            && self.elements[7] == 0.0
+            && self.elements[11] == 0.0
+            && self.elements[15] == 1.0
+    }
+
+    /// Returns the product of this matrix and another matrix, like `self *
+    /// other`, but skips the arithmetic for the bottom row, which makes it
+    /// faster for composing transformations in a scene graph.
+    ///
+    /// Both matrices must be affine (see [`Self::is_affine`]); otherwise, the
+    /// result is incorrect. This is checked in debug builds.
+    #[rustfmt::skip]
+    pub fn mul_affine(&self, other: &Self) -> Self {
+        debug_assert!(self.is_affine() && other.is_affine(), "matrices must be affine");
+
+        let a = &self.elements;
+        let b = &other.elements;
+
+        let row = |i: usize| [a[i], a[i + 4], a[i + 8]];
+        let column = |j: usize| [b[j * 4], b[j * 4 + 1], b[j * 4 + 2]];
+        let linear = |i: usize, j: usize| sum_of_products(row(i), column(j));
+        let translation = |i: usize| linear(i, 3) + a[i + 12];
+
+        Self::new(
+            linear(0, 0), linear(0, 1), linear(0, 2), translation(0),
+            linear(1, 0), linear(1, 1), linear(1, 2), translation(1),
+            linear(2, 0), linear(2, 1), linear(2, 2), translation(2),
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    /// Returns the parameters of this perspective projection matrix, e.g. to
+    /// recover the near and far planes when only the matrix was passed across
+    /// an API boundary.
+    ///
+    /// The matrix must be a right-handed perspective projection to WebGPU clip
+    /// space, i.e. looking down -Z with depth mapped to `[0, 1]`, as built by
+    /// most WebGPU libraries. An infinite far plane is recovered as infinity.
+    /// Returns `None` if the matrix is not a perspective projection.
+    pub fn perspective_params(&self) -> Option<PerspectiveParams> {
+        let e = &self.elements;
+
+        if e[11] != -1.0 || e[15] != 0.0 || e[0] == 0.0 || e[5] == 0.0 {
+            return None;
+        }
+
+        // An infinite far plane maps depth with a slope of exactly -1.
+        let far = if e[10] == -1.0 {
+            f32::INFINITY
+        } else {
+            e[14] / (e[10] + 1.0)
+        };
+
+        Some(PerspectiveParams {
+            fov_y: 2.0 * (1.0 / e[5]).atan(),
+            aspect: e[5] / e[0],
+            near: e[14] / e[10],
+            far,
+        })
+    }
+
+    /// Returns the largest absolute difference between the matching elements
+    /// of this matrix and another matrix, e.g. to compare against golden
+    /// values in tests. Returns NaN if any element of either matrix is NaN, so
+    /// that the result never passes a tolerance check.
+    pub fn max_component_difference(&self, other: &Self) -> f32 {
+        self.elements
+            .iter()
+            .zip(other.elements)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, |max, difference| {
+                // f32::max ignores NaN, which would hide it.
+                if difference.is_nan() || difference > max {
+                    difference
+                } else {
+                    max
+                }
+            })
+    }
+
+    /// Returns the angle in radians between the rotations of this
+    /// transformation matrix and another one, and the distance between their
+    /// translations.
+    ///
+    /// Unlike [`Self::max_component_difference`], the two values have a
+    /// geometric meaning, so they can be compared against meaningful
+    /// tolerances. Scale is ignored. Both matrices must be affine
+    /// transformations without skew.
+    pub fn rotation_translation_distance(&self, other: &Self) -> (f32, f32) {
+        let angle = self.rotation().angle_to(&other.rotation());
+        let distance = self.translation().distance(&other.translation());
+
+        (angle, distance)
+    }
+
+    /// Returns the rotation of this affine transformation matrix, with the
+    /// scale and, if the matrix mirrors, the mirroring removed.
+    fn rotation(&self) -> Quaternion {
+        let m = Matrix3::from_matrix4(self);
+        let sign = if m.determinant() < 0.0 { -1.0 } else { 1.0 };
+
+        let x = m[0].normalize_or_zero() * sign;
+        let y = m[1].normalize_or_zero();
+        let z = m[2].normalize_or_zero();
+
+        Quaternion::from_rotation_matrix(&Matrix3 {
+            elements: [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z],
+        })
+    }
 }
 
+/// Parameters of a perspective projection, as returned by
+/// [`Matrix4::perspective_params`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerspectiveParams {
+    /// The vertical field of view in radians.
+    pub fov_y: f32,
+    /// The aspect ratio, i.e. the width divided by the height.
+    pub aspect: f32,
+    /// The distance to the near plane.
+    pub near: f32,
+    /// The distance to the far plane.
+    pub far: f32,
+}
+
+unsafe impl Send for PerspectiveParams {}
+unsafe impl Sync for PerspectiveParams {}
+
 #[cfg(test)]
 mod tests {
     use core::f32::consts::PI;
@@ -814,6 +1116,43 @@ mod tests {
         matrix4_equals(m, expected);
     }
 
+    #[test]
+    fn test_compose_with_pivot() {
+        let translation = Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let rotation = Quaternion::from_axis_angle(&(0.0, 0.0, 1.0).into(), PI / 3.0);
+        let scale = Vector3 {
+            x: 2.0,
+            y: 3.0,
+            z: 4.0,
+        };
+        let pivot = Vector3 {
+            x: -1.0,
+            y: 5.0,
+            z: 2.0,
+        };
+
+        let m = Matrix4::compose_with_pivot(&translation, &rotation, &scale, &pivot);
+
+        let expected = Matrix4::from_translation(&translation)
+            * Matrix4::from_translation(&pivot)
+            * Matrix4::from_quaternion(&rotation)
+            * Matrix4::from_scale(&scale)
+            * Matrix4::from_translation(&-pivot);
+
+        for i in 0..16 {
+            assert_float_absolute_eq!(m.elements[i], expected.elements[i], 1e-5);
+        }
+
+        let no_pivot =
+            Matrix4::compose_with_pivot(&translation, &rotation, &scale, &Vector3::default());
+
+        matrix4_equals(no_pivot, Matrix4::compose(&translation, &rotation, &scale));
+    }
+
     #[test]
     fn test_look_at() {
         let m = Matrix4::look_at(
@@ -850,6 +1189,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_index() {
+        let mut m = Matrix4::from_translation(&(1.0, 2.0, 3.0).into());
+
+        assert_eq!(m[0], (1.0, 0.0, 0.0, 0.0).into());
+        assert_eq!(m[3], (1.0, 2.0, 3.0, 1.0).into());
+
+        m[3].y = 5.0;
+        m[1] = (0.0, 2.0, 0.0, 0.0).into();
+
+        assert_eq!(m.elements[5], 2.0);
+        assert_eq!(m.translation(), (1.0, 5.0, 3.0).into());
+    }
+
+    #[test]
+    fn test_columns_and_rows() {
+        #[rustfmt::skip]
+        let m = Matrix4::new(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0,
+        );
+
+        for (j, column) in m.columns().enumerate() {
+            let j = j as f32;
+
+            assert_eq!(column, (j + 1.0, j + 5.0, j + 9.0, j + 13.0).into());
+        }
+
+        for (i, row) in m.rows().enumerate() {
+            let i = i as f32 * 4.0;
+
+            assert_eq!(row, (i + 1.0, i + 2.0, i + 3.0, i + 4.0).into());
+        }
+
+        assert_eq!(m.columns().count(), 4);
+        assert_eq!(m.rows().count(), 4);
+    }
+
     #[test]
     fn test_translation() {
         #[rustfmt::skip]
@@ -951,6 +1330,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_matrix_addition() {
+        let mut m = Matrix4::identity();
+
+        matrix4_equals(m + Matrix4::zero(), m);
+        matrix4_equals(m + m, m * 2.0);
+
+        m += Matrix4::identity();
+        matrix4_equals(m, Matrix4::identity() * 2.0);
+    }
+
     #[test]
     fn test_determinant() {
         #[rustfmt::skip]
@@ -1012,4 +1402,173 @@ mod tests {
 
         matrix4_equals(degenerate.inverse(), Matrix4::zero());
     }
+
+    #[test]
+    fn test_map_and_zip_with() {
+        #[rustfmt::skip]
+        let m = Matrix4::new(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0,
+        );
+
+        let halved = m.map(|x| x / 2.0);
+        let product = m.zip_with(&halved, |a, b| a * b);
+
+        for i in 0..16 {
+            assert_eq!(halved.elements[i], m.elements[i] / 2.0);
+            assert_eq!(product.elements[i], m.elements[i] * m.elements[i] / 2.0);
+        }
+    }
+
+    #[test]
+    fn test_differences() {
+        let a = Matrix4::compose(
+            &(1.0, 2.0, 3.0).into(),
+            &Quaternion::from_rotation_y(0.5),
+            &(2.0, 2.0, 2.0).into(),
+        );
+        let b = Matrix4::compose(
+            &(1.0, 2.0, 3.5).into(),
+            &Quaternion::from_rotation_y(0.6),
+            &(1.0, 3.0, 1.0).into(),
+        );
+
+        assert_eq!(a.max_component_difference(&a), 0.0);
+        // The Y scales differ the most, since rotating about Y keeps the Y
+        // axis in place.
+        assert_float_absolute_eq!(a.max_component_difference(&b), 1.0);
+
+        let (angle, distance) = a.rotation_translation_distance(&b);
+
+        assert_float_absolute_eq!(angle, 0.1, 1e-5);
+        assert_float_absolute_eq!(distance, 0.5);
+
+        let mirrored = Matrix4::compose(
+            &(1.0, 2.0, 3.0).into(),
+            &Quaternion::from_rotation_y(0.5),
+            &(-1.0, 1.0, 1.0).into(),
+        );
+        let (angle, distance) = a.rotation_translation_distance(&mirrored);
+
+        assert_float_absolute_eq!(angle, 0.0, 1e-5);
+        assert_float_absolute_eq!(distance, 0.0);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let a = Matrix4::from_translation(&(1.0, 2.0, 3.0).into());
+        let b = Matrix4::from_translation(&(1.0, 2.0, 3.5).into());
+
+        assert_eq!(a.content_hash(), a.content_hash());
+        assert_ne!(a.content_hash(), b.content_hash());
+        assert_eq!(
+            Matrix4::zero().content_hash(),
+            Matrix4::zero().map(|x| -x).content_hash()
+        );
+        assert_eq!(
+            Matrix4::zero().map(|_| f32::NAN).content_hash(),
+            Matrix4::zero().map(|_| -f32::NAN).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_mul_affine() {
+        let a = Matrix4::compose(
+            &(1.0, 2.0, 3.0).into(),
+            &Quaternion::from_rotation_y(0.6),
+            &(2.0, 1.0, 0.5).into(),
+        );
+        let b = Matrix4::compose(
+            &(-4.0, 0.5, 1.0).into(),
+            &Quaternion::from_rotation_x(-1.1),
+            &(1.0, 3.0, 1.0).into(),
+        );
+
+        assert!(a.is_affine() && b.is_affine());
+        assert!(a.mul_affine(&b).max_component_difference(&(a * b)) < 1e-6);
+
+        #[rustfmt::skip]
+        let projective = Matrix4::new(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, -1.0, 0.0,
+        );
+
+        assert!(!projective.is_affine());
+    }
+
+    #[test]
+    #[cfg(feature = "wasm")]
+    fn test_to_js_array() {
+        let m = Matrix4::from_translation(&(1.0, 2.0, 3.0).into());
+        let array = m.to_js_array();
+
+        assert_eq!(array[12..15], [1.0, 2.0, 3.0]);
+        assert_eq!(array[15], 1.0);
+    }
+
+    #[test]
+    fn test_perspective_params() {
+        let (fov_y, aspect, near, far) = (1.2f32, 16.0 / 9.0, 0.1, 100.0);
+        let f = 1.0 / (fov_y / 2.0).tan();
+
+        #[rustfmt::skip]
+        let projection = Matrix4::new(
+            f / aspect, 0.0, 0.0, 0.0,
+            0.0, f, 0.0, 0.0,
+            0.0, 0.0, far / (near - far), near * far / (near - far),
+            0.0, 0.0, -1.0, 0.0,
+        );
+
+        let params = projection.perspective_params().unwrap();
+
+        assert_float_absolute_eq!(params.fov_y, fov_y, 1e-6);
+        assert_float_absolute_eq!(params.aspect, aspect, 1e-6);
+        assert_float_absolute_eq!(params.near, near, 1e-6);
+        assert_float_absolute_eq!(params.far, far, 1e-2);
+
+        #[rustfmt::skip]
+        let infinite = Matrix4::new(
+            f / aspect, 0.0, 0.0, 0.0,
+            0.0, f, 0.0, 0.0,
+            0.0, 0.0, -1.0, -near,
+            0.0, 0.0, -1.0, 0.0,
+        );
+
+        assert_eq!(infinite.perspective_params().unwrap().far, f32::INFINITY);
+        assert_eq!(Matrix4::identity().perspective_params(), None);
+    }
+
+    #[test]
+    fn test_max_component_difference_nan() {
+        let nan = Matrix4 {
+            elements: [f32::NAN; 16],
+        };
+        let mut one_nan = Matrix4::identity();
+        one_nan.elements[5] = f32::NAN;
+
+        assert!(nan.max_component_difference(&Matrix4::identity()).is_nan());
+        assert!(Matrix4::identity().max_component_difference(&nan).is_nan());
+        assert!(one_nan
+            .max_component_difference(&Matrix4::identity())
+            .is_nan());
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let m = Matrix4::from_translation(&(1.0, 2.0, 3.5).into());
+
+        assert_eq!(
+            m.to_string(),
+            "((1, 0, 0, 1), (0, 1, 0, 2), (0, 0, 1, 3.5), (0, 0, 0, 1))"
+        );
+        assert_eq!(
+            m.to_string().parse::<Matrix4>().unwrap().elements,
+            m.elements
+        );
+        assert!("((1, 0, 0, 1), (0, 1, 0, 2))".parse::<Matrix4>().is_err());
+    }
 }
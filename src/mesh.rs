@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use super::Vector3;
+
+/// Merges vertices that are within `epsilon` of each other, e.g. to rebuild
+/// shared vertices after importing a mesh that stores each triangle
+/// separately.
+///
+/// Returns the unique positions and, for each of the given positions, the
+/// index of the unique position it was merged into. The first position of
+/// every group of merged vertices is kept as is.
+///
+/// Nearby vertices are found with a spatial hash of cells of size `epsilon`,
+/// so this runs in roughly linear time.
+///
+/// # Panics
+///
+/// Panics if `epsilon` is not positive.
+pub fn weld_vertices(positions: &[Vector3], epsilon: f32) -> (Vec<Vector3>, Vec<u32>) {
+    assert!(epsilon > 0.0, "epsilon must be positive");
+
+    let cell_of = |p: &Vector3| {
+        (
+            (p.x / epsilon).floor() as i64,
+            (p.y / epsilon).floor() as i64,
+            (p.z / epsilon).floor() as i64,
+        )
+    };
+
+    let mut unique: Vec<Vector3> = Vec::new();
+    let mut indices = Vec::with_capacity(positions.len());
+    let mut cells: HashMap<(i64, i64, i64), Vec<u32>> = HashMap::new();
+
+    for p in positions {
+        let (cx, cy, cz) = cell_of(p);
+
+        // Any vertex within epsilon lies in this cell or one of its neighbors.
+        let existing = (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter_map(|(dx, dy, dz)| cells.get(&(cx + dx, cy + dy, cz + dz)))
+            .flatten()
+            .copied()
+            .find(|&i| unique[i as usize].distance(p) <= epsilon);
+
+        let index = existing.unwrap_or_else(|| {
+            let i = unique.len() as u32;
+
+            unique.push(*p);
+            cells.entry((cx, cy, cz)).or_default().push(i);
+
+            i
+        });
+
+        indices.push(index);
+    }
+
+    (unique, indices)
+}
+
+/// How face normals are weighted when they are averaged into vertex normals
+/// by [`compute_vertex_normals`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalWeighting {
+    /// Weights each face by its area, so large faces dominate. This is cheap
+    /// and works well for most meshes.
+    Area,
+    /// Weights each face by its angle at the vertex, which is independent of
+    /// how the surface is triangulated.
+    Angle,
+}
+
+unsafe impl Send for NormalWeighting {}
+unsafe impl Sync for NormalWeighting {}
+
+impl Default for NormalWeighting {
+    /// Returns the default weighting, which is by area.
+    fn default() -> Self {
+        Self::Area
+    }
+}
+
+/// Returns smooth vertex normals for the triangle mesh with the given
+/// positions and indices, where every three indices form a counterclockwise
+/// triangle.
+///
+/// Each vertex normal is the weighted average of the normals of the faces
+/// that share the vertex. Vertices that belong to no non-degenerate triangle
+/// get a zero normal. Trailing indices that do not form a whole triangle are
+/// ignored.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds of `positions`.
+pub fn compute_vertex_normals(
+    positions: &[Vector3],
+    indices: &[u32],
+    weighting: NormalWeighting,
+) -> Vec<Vector3> {
+    let mut normals = vec![Vector3::default(); positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let corners = [
+            triangle[0] as usize,
+            triangle[1] as usize,
+            triangle[2] as usize,
+        ];
+        let [a, b, c] = corners.map(|i| positions[i]);
+
+        // The length of the cross product is twice the area of the triangle.
+        let face_normal = (b - a).cross(&(c - a));
+
+        match weighting {
+            NormalWeighting::Area => {
+                for i in corners {
+                    normals[i] += face_normal;
+                }
+            }
+            NormalWeighting::Angle => {
+                let unit_normal = face_normal.normalize_or_zero();
+
+                for (i, (p, q, r)) in corners.into_iter().zip([(a, b, c), (b, c, a), (c, a, b)]) {
+                    let u = (q - p).normalize_or_zero();
+                    let v = (r - p).normalize_or_zero();
+                    let angle = u.dot(&v).clamp(-1.0, 1.0).acos();
+
+                    normals[i] += unit_normal * angle;
+                }
+            }
+        }
+    }
+
+    normals.iter().map(Vector3::normalize_or_zero).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weld_vertices() {
+        let positions: [Vector3; 5] = [
+            (0.0, 0.0, 0.0).into(),
+            (1.0, 0.0, 0.0).into(),
+            (0.0005, -0.0005, 0.0).into(),
+            (1.0, 0.0, 0.0009).into(),
+            (0.0, 1.0, 0.0).into(),
+        ];
+
+        let (unique, indices) = weld_vertices(&positions, 1e-3);
+
+        assert_eq!(unique, [positions[0], positions[1], positions[4]]);
+        assert_eq!(indices, [0, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_compute_vertex_normals() {
+        // A unit square in the XY plane facing +Z, a triangle in the XZ plane
+        // facing -Y that shares an edge with it, and an unused vertex.
+        let positions: [Vector3; 6] = [
+            (0.0, 0.0, 0.0).into(),
+            (1.0, 0.0, 0.0).into(),
+            (1.0, 1.0, 0.0).into(),
+            (0.0, 1.0, 0.0).into(),
+            (0.0, 0.0, -2.0).into(),
+            (5.0, 5.0, 5.0).into(),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 0, 4, 1];
+
+        let area = compute_vertex_normals(&positions, &indices, NormalWeighting::Area);
+        let angle = compute_vertex_normals(&positions, &indices, NormalWeighting::Angle);
+
+        assert_eq!(area.len(), positions.len());
+        assert_eq!(area[2], (0.0, 0.0, 1.0).into());
+        assert_eq!(angle[3], (0.0, 0.0, 1.0).into());
+        assert_eq!(area[5], Vector3::default());
+
+        // Vertex 0 sees the XY plane with a total area of 1 and a total angle
+        // of π/2, and the XZ plane, facing -Y, with an area of 1 and an angle
+        // of π/2.
+        let expected = Vector3::from((0.0, -1.0, 1.0)).normalized();
+
+        for normal in [area[0], angle[0]] {
+            assert!((normal - expected).length() < 1e-6);
+        }
+
+        // Vertex 1 sees the XY plane with an area of 0.5 and an angle of π/2,
+        // and the XZ plane with an area of 1 and an angle of atan(2), so the
+        // weightings disagree on which plane dominates.
+        assert!(-area[1].y > area[1].z);
+        assert!(angle[1].z > -angle[1].y);
+    }
+
+    #[test]
+    fn test_weld_vertices_empty() {
+        let (unique, indices) = weld_vertices(&[], 1e-3);
+
+        assert!(unique.is_empty());
+        assert!(indices.is_empty());
+    }
+}
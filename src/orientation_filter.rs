@@ -0,0 +1,151 @@
+use std::f32::consts::TAU;
+
+use super::Quaternion;
+
+/// Adaptive low-pass filter for noisy orientations, e.g. from head or hand
+/// tracking, based on the [1€ filter][one-euro].
+///
+/// Each update moves the filtered orientation towards the measurement by an
+/// exponential moving average in log space, i.e. along the shortest arc. The
+/// cutoff frequency rises with the angular speed, so slow movements are
+/// smoothed strongly to remove jitter while fast movements are followed with
+/// little lag.
+///
+/// [one-euro]: https://gery.casiez.net/1euro/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientationFilter {
+    /// The cutoff frequency in hertz when the orientation is still. Lower
+    /// values remove more jitter but add more lag.
+    pub min_cutoff: f32,
+    /// How much the cutoff frequency increases per radian per second of
+    /// angular speed. Higher values reduce lag during fast movements.
+    pub beta: f32,
+    /// The cutoff frequency in hertz for smoothing the angular speed.
+    pub speed_cutoff: f32,
+    value: Option<Quaternion>,
+    speed: f32,
+}
+
+unsafe impl Send for OrientationFilter {}
+unsafe impl Sync for OrientationFilter {}
+
+impl OrientationFilter {
+    /// Creates a new filter with the given minimum cutoff frequency in hertz
+    /// and speed coefficient. The angular speed is smoothed with a cutoff of
+    /// 1 Hz.
+    pub fn new(min_cutoff: f32, beta: f32) -> Self {
+        Self {
+            min_cutoff,
+            beta,
+            speed_cutoff: 1.0,
+            value: None,
+            speed: 0.0,
+        }
+    }
+
+    /// Returns the filtered orientation, or `None` before the first update.
+    pub fn value(&self) -> Option<Quaternion> {
+        self.value
+    }
+
+    /// Filters the given normalized orientation, measured the given time step
+    /// in seconds after the previous one, and returns the new filtered
+    /// orientation.
+    ///
+    /// The first measurement is returned as is. If the time step is not
+    /// positive, the filtered orientation is returned unchanged.
+    pub fn update(&mut self, measurement: &Quaternion, dt: f32) -> Quaternion {
+        let Some(value) = self.value else {
+            self.value = Some(*measurement);
+            return *measurement;
+        };
+
+        if dt <= 0.0 {
+            return value;
+        }
+
+        let raw_speed = value.angle_to(measurement) / dt;
+        self.speed += (raw_speed - self.speed) * smoothing_factor(self.speed_cutoff, dt);
+
+        let cutoff = self.min_cutoff + self.beta * self.speed;
+        let filtered =
+            Quaternion::slerp(&value, measurement, smoothing_factor(cutoff, dt)).normalized();
+
+        self.value = Some(filtered);
+
+        filtered
+    }
+
+    /// Clears the filtered orientation, so that the next measurement is
+    /// returned as is, e.g. after tracking was lost.
+    pub fn reset(&mut self) {
+        self.value = None;
+        self.speed = 0.0;
+    }
+}
+
+/// Returns the smoothing factor of an exponential moving average with the
+/// given cutoff frequency in hertz and time step in seconds.
+fn smoothing_factor(cutoff: f32, dt: f32) -> f32 {
+    let r = TAU * cutoff * dt;
+
+    r / (r + 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orientation_filter_smooths_jitter() {
+        let mut filter = OrientationFilter::new(1.0, 0.0);
+        let target = Quaternion::from_rotation_y(0.5);
+
+        assert_eq!(filter.value(), None);
+        assert_eq!(filter.update(&target, 1.0 / 60.0), target);
+
+        // Alternating noise of ±0.05 radians is mostly removed.
+        for i in 0..120 {
+            let noise = if i % 2 == 0 { 0.05 } else { -0.05 };
+            let filtered = filter.update(&Quaternion::from_rotation_y(0.5 + noise), 1.0 / 60.0);
+
+            assert!(filtered.angle_to(&target) < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_orientation_filter_follows_fast_movement() {
+        let mut slow = OrientationFilter::new(1.0, 0.0);
+        let mut adaptive = OrientationFilter::new(1.0, 1.0);
+
+        for filter in [&mut slow, &mut adaptive] {
+            filter.update(&Quaternion::IDENTITY, 1.0 / 60.0);
+        }
+
+        // Rotate at 6 radians per second for 10 frames.
+        let mut measurement = Quaternion::IDENTITY;
+
+        for i in 1..=10 {
+            measurement = Quaternion::from_rotation_z(0.1 * i as f32);
+            slow.update(&measurement, 1.0 / 60.0);
+            adaptive.update(&measurement, 1.0 / 60.0);
+        }
+
+        let slow_lag = slow.value().unwrap().angle_to(&measurement);
+        let adaptive_lag = adaptive.value().unwrap().angle_to(&measurement);
+
+        assert!(adaptive_lag < slow_lag);
+    }
+
+    #[test]
+    fn test_orientation_filter_reset() {
+        let mut filter = OrientationFilter::new(1.0, 0.0);
+
+        filter.update(&Quaternion::IDENTITY, 1.0 / 60.0);
+        filter.reset();
+
+        let q = Quaternion::from_rotation_x(1.0);
+
+        assert_eq!(filter.update(&q, 1.0 / 60.0), q);
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Quaternion, Vector3};
+
+/// Error between an original track of values and its quantized or compressed
+/// version, e.g. to report the quality of animation compression.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QuantizationError {
+    /// The root mean square of the per-sample errors.
+    pub rmse: f32,
+    /// The largest per-sample error.
+    pub max: f32,
+}
+
+unsafe impl Send for QuantizationError {}
+unsafe impl Sync for QuantizationError {}
+
+impl QuantizationError {
+    /// Returns the error between the original and quantized vectors, where
+    /// the error of each sample is the distance between the two vectors.
+    ///
+    /// Returns zero errors if there are no samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `original` and `quantized` have different lengths.
+    pub fn of_vectors(original: &[Vector3], quantized: &[Vector3]) -> Self {
+        assert_eq!(
+            original.len(),
+            quantized.len(),
+            "tracks must have the same number of samples"
+        );
+
+        Self::from_errors(original.iter().zip(quantized).map(|(a, b)| a.distance(b)))
+    }
+
+    /// Returns the error between the original and quantized rotations, where
+    /// the error of each sample is the angle in radians between the two
+    /// rotations. All quaternions must be normalized.
+    ///
+    /// Returns zero errors if there are no samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `original` and `quantized` have different lengths.
+    pub fn of_rotations(original: &[Quaternion], quantized: &[Quaternion]) -> Self {
+        assert_eq!(
+            original.len(),
+            quantized.len(),
+            "tracks must have the same number of samples"
+        );
+
+        Self::from_errors(original.iter().zip(quantized).map(|(a, b)| a.angle_to(b)))
+    }
+
+    /// Returns the statistics of the given per-sample errors.
+    fn from_errors(errors: impl Iterator<Item = f32>) -> Self {
+        let (count, square_sum, max) = errors.fold((0, 0.0, 0.0f32), |(n, sum, max), e| {
+            (n + 1, sum + e * e, max.max(e))
+        });
+
+        if count == 0 {
+            return Self::default();
+        }
+
+        Self {
+            rmse: (square_sum / count as f32).sqrt(),
+            max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_of_vectors() {
+        let original: [Vector3; 2] = [(0.0, 0.0, 0.0).into(), (1.0, 1.0, 1.0).into()];
+        let quantized: [Vector3; 2] = [(0.0, 0.3, 0.4).into(), (1.0, 1.0, 1.0).into()];
+
+        let error = QuantizationError::of_vectors(&original, &quantized);
+
+        assert_float_absolute_eq!(error.rmse, (0.25f32 / 2.0).sqrt());
+        assert_float_absolute_eq!(error.max, 0.5);
+
+        assert_eq!(
+            QuantizationError::of_vectors(&[], &[]),
+            QuantizationError::default()
+        );
+    }
+
+    #[test]
+    fn test_of_rotations() {
+        let original = [Quaternion::from_rotation_x(0.5), Quaternion::default()];
+        let quantized = [Quaternion::from_rotation_x(0.52), -Quaternion::default()];
+
+        let error = QuantizationError::of_rotations(&original, &quantized);
+
+        assert_float_absolute_eq!(error.rmse, (0.0004f32 / 2.0).sqrt(), 1e-5);
+        assert_float_absolute_eq!(error.max, 0.02, 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        QuantizationError::of_vectors(&[Vector3::default()], &[]);
+    }
+}
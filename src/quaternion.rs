@@ -1,6 +1,8 @@
-use std::ops;
+use std::{fmt, iter, ops, str::FromStr};
 
-use super::{Euler, EulerOrder, Vector3};
+use crate::format::{parse_components, write_components};
+
+use super::{AxisAngle, Euler, EulerOrder, Matrix3, NotEnoughElementsError, ParseError, Vector3};
 
 /// Quaternion, which can be used to represent rotations around arbitrary axes.
 ///
@@ -27,7 +29,8 @@ use super::{Euler, EulerOrder, Vector3};
 ///
 /// - Use [`Quaternion::from`] if you already have the Euler angles.
 /// - Use [`Quaternion::from_axis_angle`] for a rotation around an arbitrary
-///   axis.
+///   axis, or [`Quaternion::from_rotation_x`] and its Y and Z counterparts for
+///   a rotation around a coordinate axis.
 /// - Or manually instantiate a new struct if you already have the components.
 ///
 /// ## Quaternion operations
@@ -58,8 +61,13 @@ use super::{Euler, EulerOrder, Vector3};
 ///
 /// ## Supported operators
 ///
-/// - [`ops::Mul`]
-/// - [`ops::MulAssign`]
+/// - [`ops::Mul`], [`ops::MulAssign`]
+///   - Quaternion multiplication (Hamilton product)
+///   - Component-wise multiplication by a scalar (commutative)
+/// - [`ops::Add`], [`ops::AddAssign`]
+///   - Component-wise addition, e.g. for weighted blending
+/// - [`ops::Neg`]
+///   - Component-wise negation, which represents the same rotation
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Quaternion {
@@ -75,17 +83,51 @@ unsafe impl Sync for Quaternion {}
 impl Default for Quaternion {
     /// Returns the identity quaternion (i.e. no rotation).
     fn default() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 1.0,
-        }
+        Self::IDENTITY
     }
 }
 
 impl Eq for Quaternion {}
 
+impl fmt::Display for Quaternion {
+    /// Formats this quaternion as `(x, y, z, w)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+impl FromStr for Quaternion {
+    type Err = ParseError;
+
+    /// Parses a quaternion in the format written by `Display`, e.g.
+    /// `(x, y, z, w)`. The parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z, w] = parse_components::<f32, 4>(s)?;
+
+        Ok(Self { x, y, z, w })
+    }
+}
+
+impl iter::Product for Quaternion {
+    /// Returns the product of the quaternions in order, i.e. `a * b * c` for
+    /// `[a, b, c]`, which applies `c` first. Returns the identity rotation if
+    /// there are no quaternions.
+    fn product<I: Iterator<Item = Quaternion>>(iter: I) -> Self {
+        iter.fold(Quaternion::default(), |acc, q| acc * q)
+    }
+}
+
+impl<'a> iter::Product<&'a Quaternion> for Quaternion {
+    /// Returns the product of the quaternions in order, i.e. `a * b * c` for
+    /// `[a, b, c]`, which applies `c` first. Returns the identity rotation if
+    /// there are no quaternions.
+    fn product<I: Iterator<Item = &'a Quaternion>>(iter: I) -> Self {
+        iter.fold(Quaternion::default(), |acc, q| acc * q)
+    }
+}
+
 impl From<&Euler> for Quaternion {
     /// Converts the given Euler angles to a rotation quaternion.
     fn from(euler: &Euler) -> Self {
@@ -156,7 +198,56 @@ impl_op_ex!(*= |a: &mut Quaternion, b: &Quaternion| {
     *a = *a * b;
 });
 
+impl_op_ex_commutative!(*|q: &Quaternion, s: &f32| -> Quaternion {
+    Quaternion {
+        x: q.x * s,
+        y: q.y * s,
+        z: q.z * s,
+        w: q.w * s,
+    }
+});
+
+impl_op_ex!(*= |q: &mut Quaternion, s: &f32| {
+    q.x *= s;
+    q.y *= s;
+    q.z *= s;
+    q.w *= s;
+});
+
+impl_op_ex!(+|a: &Quaternion, b: &Quaternion| -> Quaternion {
+    Quaternion {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+        w: a.w + b.w,
+    }
+});
+
+impl_op_ex!(+= |a: &mut Quaternion, b: &Quaternion| {
+    a.x += b.x;
+    a.y += b.y;
+    a.z += b.z;
+    a.w += b.w;
+});
+
+impl_op_ex!(-|q: &Quaternion| -> Quaternion {
+    Quaternion {
+        x: -q.x,
+        y: -q.y,
+        z: -q.z,
+        w: -q.w,
+    }
+});
+
 impl Quaternion {
+    /// The identity quaternion (i.e. no rotation).
+    pub const IDENTITY: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+
     /// Creates a new quaternion for the rotation by the given angle around the
     /// given axis. The axis must be normalized and the angle must be in
     /// radians.
@@ -171,6 +262,327 @@ impl Quaternion {
         }
     }
 
+    /// Creates a new quaternion for the rotation by the given angle, in
+    /// radians, around the X axis.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (s, c) = (angle / 2.0).sin_cos();
+
+        Self {
+            x: s,
+            y: 0.0,
+            z: 0.0,
+            w: c,
+        }
+    }
+
+    /// Creates a new quaternion for the rotation by the given angle, in
+    /// radians, around the Y axis.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (s, c) = (angle / 2.0).sin_cos();
+
+        Self {
+            x: 0.0,
+            y: s,
+            z: 0.0,
+            w: c,
+        }
+    }
+
+    /// Creates a new quaternion for the rotation by the given angle, in
+    /// radians, around the Z axis.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (s, c) = (angle / 2.0).sin_cos();
+
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: s,
+            w: c,
+        }
+    }
+
+    /// Creates a new quaternion for the shortest rotation that takes the unit
+    /// vector `from` onto the unit vector `to`.
+    ///
+    /// If the vectors point in opposite directions, the result is a half turn
+    /// around an arbitrary axis perpendicular to `from`.
+    pub fn from_rotation_arc(from: &Vector3, to: &Vector3) -> Self {
+        let r = from.dot(to) + 1.0;
+
+        let mut q = if r < f32::EPSILON {
+            let axis = if from.x.abs() > from.z.abs() {
+                Vector3 {
+                    x: -from.y,
+                    y: from.x,
+                    z: 0.0,
+                }
+            } else {
+                Vector3 {
+                    x: 0.0,
+                    y: -from.z,
+                    z: from.y,
+                }
+            };
+
+            Self {
+                x: axis.x,
+                y: axis.y,
+                z: axis.z,
+                w: 0.0,
+            }
+        } else {
+            let axis = from.cross(to);
+
+            Self {
+                x: axis.x,
+                y: axis.y,
+                z: axis.z,
+                w: r,
+            }
+        };
+
+        q.normalize();
+
+        q
+    }
+
+    /// Creates a new quaternion for the rotation that makes the -Z axis face
+    /// the given forward direction, with the +Y axis as close as possible to
+    /// the given up direction. This matches the right-handed, Y-up convention
+    /// of WebGPU cameras.
+    ///
+    /// If `forward` and `up` are parallel, an arbitrary perpendicular up
+    /// direction is used instead.
+    pub fn look_rotation(forward: &Vector3, up: &Vector3) -> Self {
+        let z = (-forward).normalized();
+        let mut x = up.cross(&z);
+
+        if x.length() == 0.0 {
+            let fallback = if z.x.abs() < 0.9 {
+                Vector3 {
+                    x: 1.0,
+                    y: 0.0,
+                    z: 0.0,
+                }
+            } else {
+                Vector3 {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 1.0,
+                }
+            };
+
+            x = z.cross(&fallback);
+        }
+
+        let x = x.normalized();
+        let y = z.cross(&x);
+
+        #[rustfmt::skip]
+        let m = Matrix3::new(
+            x.x, y.x, z.x,
+            x.y, y.y, z.y,
+            x.z, y.z, z.z,
+        );
+
+        Self::from_rotation_matrix(&m)
+    }
+
+    /// Creates a new quaternion from the given rotation matrix, which must be a
+    /// pure rotation i.e. unscaled.
+    ///
+    /// The implementation is based on [this page][matquat].
+    ///
+    /// [matquat]: https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/index.htm
+    pub fn from_rotation_matrix(m: &Matrix3) -> Self {
+        let m11 = m.elements[0];
+        let m21 = m.elements[1];
+        let m31 = m.elements[2];
+        let m12 = m.elements[3];
+        let m22 = m.elements[4];
+        let m32 = m.elements[5];
+        let m13 = m.elements[6];
+        let m23 = m.elements[7];
+        let m33 = m.elements[8];
+
+        let trace = m11 + m22 + m33;
+
+        if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+
+            Self {
+                x: (m32 - m23) * s,
+                y: (m13 - m31) * s,
+                z: (m21 - m12) * s,
+                w: 0.25 / s,
+            }
+        } else if m11 > m22 && m11 > m33 {
+            let s = 2.0 * (1.0 + m11 - m22 - m33).sqrt();
+
+            Self {
+                x: 0.25 * s,
+                y: (m12 + m21) / s,
+                z: (m13 + m31) / s,
+                w: (m32 - m23) / s,
+            }
+        } else if m22 > m33 {
+            let s = 2.0 * (1.0 + m22 - m11 - m33).sqrt();
+
+            Self {
+                x: (m12 + m21) / s,
+                y: 0.25 * s,
+                z: (m23 + m32) / s,
+                w: (m13 - m31) / s,
+            }
+        } else {
+            let s = 2.0 * (1.0 + m33 - m11 - m22).sqrt();
+
+            Self {
+                x: (m13 + m31) / s,
+                y: (m23 + m32) / s,
+                z: 0.25 * s,
+                w: (m21 - m12) / s,
+            }
+        }
+    }
+
+    /// Returns the rotation matrix of this quaternion, which must be
+    /// normalized, with each column padded to four floats.
+    ///
+    /// This matches the memory layout of `mat3x3<f32>` in WGSL, so the result
+    /// can be written directly to a GPU buffer without building a [`Matrix4`]
+    /// first. The elements are in column-major order and every fourth element
+    /// is `0.0`.
+    ///
+    /// [`Matrix4`]: super::Matrix4
+    pub fn to_mat3_padded(&self) -> [f32; 12] {
+        let x2 = self.x + self.x;
+        let y2 = self.y + self.y;
+        let z2 = self.z + self.z;
+
+        let xx = self.x * x2;
+        let xy = self.x * y2;
+        let xz = self.x * z2;
+        let yy = self.y * y2;
+        let yz = self.y * z2;
+        let zz = self.z * z2;
+        let wx = self.w * x2;
+        let wy = self.w * y2;
+        let wz = self.w * z2;
+
+        #[rustfmt::skip]
+        let elements = [
+            1.0 - (yy + zz), xy + wz,         xz - wy,         0.0,
+            xy - wz,         1.0 - (xx + zz), yz + wx,         0.0,
+            xz + wy,         yz - wx,         1.0 - (xx + yy), 0.0,
+        ];
+
+        elements
+    }
+
+    /// Returns the continuous 6D representation of this rotation, which is the
+    /// first two columns of its rotation matrix. This quaternion must be
+    /// normalized.
+    ///
+    /// Unlike quaternions and Euler angles, the 6D representation has no
+    /// discontinuities or double cover, which makes it well suited as the
+    /// output of neural networks. See [Zhou et al., "On the Continuity of
+    /// Rotation Representations in Neural Networks"][6d] (2019).
+    ///
+    /// [6d]: https://arxiv.org/abs/1812.07035
+    pub fn to_6d(&self) -> [f32; 6] {
+        let m = self.to_mat3_padded();
+
+        [m[0], m[1], m[2], m[4], m[5], m[6]]
+    }
+
+    /// Creates a new quaternion from the given 6D representation, e.g. one
+    /// predicted by a neural network. See [`Quaternion::to_6d`].
+    ///
+    /// The two columns need not be normalized or orthogonal: they are
+    /// orthonormalized with the Gram-Schmidt process, so that the first
+    /// column keeps its direction. They must not be parallel.
+    pub fn from_6d(v: &[f32; 6]) -> Self {
+        let a = Vector3::from([v[0], v[1], v[2]]);
+        let b = Vector3::from([v[3], v[4], v[5]]);
+
+        let x = a.normalized();
+        let y = (b - x * x.dot(&b)).normalized();
+        let z = x.cross(&y);
+
+        Self::from_rotation_matrix(&Matrix3 {
+            elements: [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z],
+        })
+    }
+
+    /// Returns `n` rotation quaternions that are spread near-uniformly over
+    /// the space of all orientations, e.g. to render an object from many
+    /// directions when baking impostors.
+    ///
+    /// The samples are generated with the super-Fibonacci spiral described in
+    /// [Alexa, "Super-Fibonacci Spirals"][sf] (CVPR 2022), so any number of
+    /// samples can be requested and the result is deterministic.
+    ///
+    /// [sf]: https://openaccess.thecvf.com/content/CVPR2022/papers/Alexa_Super-Fibonacci_Spirals_Fast_Low-Discrepancy_Sampling_of_SO3_CVPR_2022_paper.pdf
+    pub fn super_fibonacci(n: usize) -> Vec<Self> {
+        const PHI: f64 = std::f64::consts::SQRT_2;
+        const PSI: f64 = 1.533_751_168_755_204_3;
+
+        (0..n)
+            .map(|i| {
+                let s = i as f64 + 0.5;
+                let t = s / n as f64;
+
+                let r = t.sqrt();
+                let big_r = (1.0 - t).sqrt();
+                let alpha = std::f64::consts::TAU * s / PHI;
+                let beta = std::f64::consts::TAU * s / PSI;
+
+                Self {
+                    x: (r * alpha.sin()) as f32,
+                    y: (r * alpha.cos()) as f32,
+                    z: (big_r * beta.sin()) as f32,
+                    w: (big_r * beta.cos()) as f32,
+                }
+            })
+            .collect()
+    }
+
+    /// Estimates the angular velocity from the given timestamped orientation
+    /// samples, e.g. to smooth tracked controller input.
+    ///
+    /// Each sample is a time in seconds and a rotation quaternion. The samples
+    /// must be sorted by time. The rotations between consecutive samples are
+    /// taken along the shortest path and averaged over the elapsed time, so
+    /// the result is the world-space rotation axis scaled by the angular speed
+    /// in radians per second.
+    ///
+    /// Returns the zero vector if there are fewer than two samples or no time
+    /// has elapsed.
+    pub fn velocity_from_samples(samples: &[(f32, Quaternion)]) -> Vector3 {
+        let mut rotation = Vector3::default();
+        let mut elapsed = 0.0;
+
+        for pair in samples.windows(2) {
+            let (t0, q0) = pair[0];
+            let (t1, q1) = pair[1];
+            let dt = t1 - t0;
+
+            if dt <= 0.0 {
+                continue;
+            }
+
+            rotation += AxisAngle::from(&(q1 * q0.conjugate())).to_rotation_vector();
+            elapsed += dt;
+        }
+
+        if elapsed > 0.0 {
+            rotation / elapsed
+        } else {
+            Vector3::default()
+        }
+    }
+
     /// Sets the x, y, z, and w properties.
     pub fn set(&mut self, x: f32, y: f32, z: f32, w: f32) {
         self.x = x;
@@ -179,6 +591,28 @@ impl Quaternion {
         self.w = w;
     }
 
+    /// Creates a new quaternion from the first four floats of the given
+    /// iterator, in x, y, z, w order. Any remaining floats are left in the
+    /// iterator.
+    ///
+    /// Returns an error if the iterator yields fewer than four floats.
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = f32>,
+    ) -> Result<Self, NotEnoughElementsError> {
+        let mut iter = iter.into_iter();
+        let mut next = |found| {
+            iter.next()
+                .ok_or(NotEnoughElementsError { expected: 4, found })
+        };
+
+        Ok(Self {
+            x: next(0)?,
+            y: next(1)?,
+            z: next(2)?,
+            w: next(3)?,
+        })
+    }
+
     /// Returns the norm. The norm of a quaternion has no inherent geometric
     /// meaning, but all rotation quaternions must have a norm of `1.0`.
     pub fn norm(&self) -> f32 {
@@ -195,6 +629,306 @@ impl Quaternion {
         self.z /= norm;
     }
 
+    /// Returns the normalized version of this quaternion.
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+
+        Self {
+            x: self.x / norm,
+            y: self.y / norm,
+            z: self.z / norm,
+            w: self.w / norm,
+        }
+    }
+
+    /// Returns whether the norm of this quaternion is within `epsilon` of
+    /// `1.0`.
+    pub fn is_normalized(&self, epsilon: f32) -> bool {
+        (self.norm() - 1.0).abs() <= epsilon
+    }
+
+    /// Returns the normalized linear interpolation between the rotations `a`
+    /// and `b` by the factor `t`, where `t = 0.0` returns `a` and `t = 1.0`
+    /// returns `b`.
+    ///
+    /// The components are interpolated linearly along the shortest path and
+    /// the result is normalized. This does not rotate at a constant angular
+    /// speed, but it is cheap and works well for blending many poses, e.g. in
+    /// skeletal animation.
+    pub fn nlerp(a: &Quaternion, b: &Quaternion, t: f32) -> Self {
+        let sign = if a.dot(b) < 0.0 { -1.0 } else { 1.0 };
+
+        let mut q = Self {
+            x: a.x + (sign * b.x - a.x) * t,
+            y: a.y + (sign * b.y - a.y) * t,
+            z: a.z + (sign * b.z - a.z) * t,
+            w: a.w + (sign * b.w - a.w) * t,
+        };
+        q.normalize();
+
+        q
+    }
+
+    /// Returns the spherical linear interpolation between the rotations `a`
+    /// and `b` by the factor `t`, where `t = 0.0` returns `a` and `t = 1.0`
+    /// returns `b`.
+    ///
+    /// The rotation is interpolated along the shortest path at a constant
+    /// angular speed. Both quaternions must be normalized.
+    pub fn slerp(a: &Quaternion, b: &Quaternion, t: f32) -> Self {
+        let dot = a.dot(b);
+        let cos = dot.abs();
+
+        // Nearly identical rotations would divide by a sine close to zero.
+        if cos > 0.9995 {
+            return Self::nlerp(a, b, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let wa = ((1.0 - t) * theta).sin() / sin;
+        let wb = (t * theta).sin() / sin * dot.signum();
+
+        Self {
+            x: a.x * wa + b.x * wb,
+            y: a.y * wa + b.y * wb,
+            z: a.z * wa + b.z * wb,
+            w: a.w * wa + b.w * wb,
+        }
+    }
+
+    /// Writes the [spherical linear interpolation](Self::slerp) of each pair
+    /// of rotations in `a` and `b` by the factor `t` to the matching element
+    /// of `out`, e.g. to crossfade a whole skinning palette in one call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a`, `b`, and `out` do not all have the same length.
+    pub fn slerp_slice(a: &[Quaternion], b: &[Quaternion], t: f32, out: &mut [Quaternion]) {
+        assert!(
+            a.len() == b.len() && a.len() == out.len(),
+            "slices must have the same length"
+        );
+
+        for ((a, b), out) in a.iter().zip(b).zip(out) {
+            *out = Self::slerp(a, b, t);
+        }
+    }
+
+    /// Returns the spherical cubic interpolation (squad) between the rotations
+    /// `q0` and `q1` by the factor `t`, using the inner control points `a` and
+    /// `b`.
+    ///
+    /// Interpolating keyframes `q[i]` to `q[i + 1]` with
+    /// `a = Quaternion::squad_control_point(&q[i - 1], &q[i], &q[i + 1])` and
+    /// `b = Quaternion::squad_control_point(&q[i], &q[i + 1], &q[i + 2])`
+    /// gives a smooth (C1-continuous) rotation across the keyframes, unlike
+    /// piecewise [`Self::slerp`]. All quaternions must be normalized.
+    pub fn squad(q0: &Quaternion, a: &Quaternion, b: &Quaternion, q1: &Quaternion, t: f32) -> Self {
+        Self::slerp(
+            &Self::slerp(q0, q1, t),
+            &Self::slerp(a, b, t),
+            2.0 * t * (1.0 - t),
+        )
+    }
+
+    /// Returns the inner control point of the keyframe `current` for
+    /// [`Self::squad`], given the keyframes before and after it. For the first
+    /// and last keyframes, pass the keyframe itself as the missing neighbor.
+    pub fn squad_control_point(prev: &Quaternion, current: &Quaternion, next: &Quaternion) -> Self {
+        let same_hemisphere = |q: &Quaternion| {
+            let sign = if current.dot(q) < 0.0 { -1.0 } else { 1.0 };
+
+            Quaternion {
+                x: q.x * sign,
+                y: q.y * sign,
+                z: q.z * sign,
+                w: q.w * sign,
+            }
+        };
+
+        let inverse = current.conjugate();
+        let to_next = (inverse * same_hemisphere(next)).log();
+        let to_prev = (inverse * same_hemisphere(prev)).log();
+
+        current * Self::exp(&((to_next + to_prev) / -4.0))
+    }
+
+    /// Returns the logarithm of this unit quaternion, which is a pure
+    /// quaternion represented by its vector part: the rotation axis scaled by
+    /// half the rotation angle.
+    fn log(&self) -> Vector3 {
+        let v = Vector3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let sin = v.length();
+
+        if sin == 0.0 {
+            return Vector3::default();
+        }
+
+        v * (sin.atan2(self.w) / sin)
+    }
+
+    /// Returns the exponential of the given pure quaternion, represented by
+    /// its vector part. This is the inverse of [`Self::log`].
+    fn exp(v: &Vector3) -> Self {
+        let theta = v.length();
+
+        if theta == 0.0 {
+            return Self::default();
+        }
+
+        let s = theta.sin() / theta;
+
+        Self {
+            x: v.x * s,
+            y: v.y * s,
+            z: v.z * s,
+            w: theta.cos(),
+        }
+    }
+
+    /// Returns the dot product of this quaternion with another quaternion.
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Returns the angle in radians of the shortest rotation from this
+    /// rotation to the given rotation. Both quaternions must be normalized.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        let d = other * self.conjugate();
+        let sin = (d.x * d.x + d.y * d.y + d.z * d.z).sqrt();
+
+        2.0 * sin.atan2(d.w.abs())
+    }
+
+    /// Returns this rotation rotated towards the given rotation by at most
+    /// `max_radians`, e.g. to turn a turret at a bounded angular speed each
+    /// frame. The target rotation is returned once it is within reach. Both
+    /// quaternions must be normalized.
+    pub fn rotate_towards(&self, other: &Self, max_radians: f32) -> Self {
+        let angle = self.angle_to(other);
+
+        if angle <= max_radians {
+            *other
+        } else if max_radians <= 0.0 {
+            *self
+        } else {
+            Self::slerp(self, other, max_radians / angle)
+        }
+    }
+
+    /// Decomposes this rotation into a swing and a twist around the given
+    /// axis, such that `self == swing * twist`. The twist is the part of the
+    /// rotation around the axis, and the swing is a rotation around an axis
+    /// perpendicular to it. The axis must be normalized.
+    ///
+    /// Returns `(swing, twist)`.
+    pub fn swing_twist(&self, axis: &Vector3) -> (Self, Self) {
+        let projection = axis * (self.x * axis.x + self.y * axis.y + self.z * axis.z);
+
+        let mut twist = Self {
+            x: projection.x,
+            y: projection.y,
+            z: projection.z,
+            w: self.w,
+        };
+
+        // A half turn swing leaves no twist to speak of.
+        if twist.norm() == 0.0 {
+            twist = Self::default();
+        } else {
+            twist.normalize();
+        }
+
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Returns this rotation with the rotation around disallowed axes removed,
+    /// e.g. for gizmos or physics constraints. `axes` tells whether rotation
+    /// around the X, Y, and Z axis, respectively, is allowed.
+    ///
+    /// - If one axis is allowed, only the twist around that axis is kept.
+    /// - If two axes are allowed, the twist around the third axis is removed.
+    ///
+    /// See [`Self::swing_twist`].
+    pub fn constrained(&self, axes: [bool; 3]) -> Self {
+        let unit_axes: [Vector3; 3] = [
+            (1.0, 0.0, 0.0).into(),
+            (0.0, 1.0, 0.0).into(),
+            (0.0, 0.0, 1.0).into(),
+        ];
+
+        match axes.iter().filter(|&&allowed| allowed).count() {
+            0 => Self::default(),
+            1 => {
+                let allowed = axes.iter().position(|&allowed| allowed).unwrap_or(0);
+
+                self.swing_twist(&unit_axes[allowed]).1
+            }
+            2 => {
+                let disallowed = axes.iter().position(|&allowed| !allowed).unwrap_or(0);
+
+                self.swing_twist(&unit_axes[disallowed]).0
+            }
+            _ => *self,
+        }
+    }
+
+    /// Returns the components of this quaternion as `f64`s in `[x, y, z, w]`
+    /// order, which is the layout that JavaScript libraries such as three.js
+    /// and gl-matrix use.
+    #[cfg(feature = "wasm")]
+    pub fn to_js_array(&self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w].map(f64::from)
+    }
+
+    /// Returns the canonical representation of this quaternion, which is the
+    /// one of `q` and `-q` with `w >= 0.0`. Both represent the same rotation,
+    /// so this gives every rotation a single representation, e.g. for hashing,
+    /// serialization, or animation compression.
+    ///
+    /// If `w` is zero, the first non-zero component among `x`, `y`, and `z` is
+    /// made positive instead.
+    pub fn canonicalized(&self) -> Self {
+        let leading = [self.w, self.x, self.y, self.z]
+            .into_iter()
+            .find(|&c| c != 0.0)
+            .unwrap_or(0.0);
+
+        if leading < 0.0 {
+            -self
+        } else {
+            *self
+        }
+    }
+
+    /// Returns a debug representation of this quaternion that shows the axis
+    /// and the angle in degrees of its rotation alongside the components,
+    /// which are hard to interpret on their own, e.g.
+    /// `Quaternion { x: 0.0, y: 0.0, z: 0.70710677, w: 0.70710677, axis: (0.0, 0.0, 1.0), angle: 90.0° }`.
+    ///
+    /// The axis and angle are as returned by [`AxisAngle::from`], with the
+    /// angle between `0°` and `180°`.
+    pub fn debug_axis_angle(&self) -> String {
+        let AxisAngle { axis, angle } = AxisAngle::from(self);
+
+        format!(
+            "Quaternion {{ x: {:?}, y: {:?}, z: {:?}, w: {:?}, axis: ({:?}, {:?}, {:?}), angle: {:?}° }}",
+            self.x,
+            self.y,
+            self.z,
+            self.w,
+            axis.x,
+            axis.y,
+            axis.z,
+            angle.to_degrees()
+        )
+    }
+
     /// Returns the conjugate. The conjugate represents the same rotation in the
     /// opposite direction.
     pub fn conjugate(&self) -> Self {
@@ -218,6 +952,7 @@ mod tests {
     use core::f32;
 
     use super::*;
+    use crate::Matrix4;
     use assert_float_eq::assert_float_absolute_eq;
 
     #[test]
@@ -239,6 +974,201 @@ mod tests {
         assert_eq!(a, b1 * b2);
     }
 
+    #[test]
+    fn test_from_rotation_xyz() {
+        let angle = 0.7;
+
+        assert_eq!(
+            Quaternion::from_rotation_x(angle),
+            Quaternion::from_axis_angle(&(1.0, 0.0, 0.0).into(), angle)
+        );
+        assert_eq!(
+            Quaternion::from_rotation_y(angle),
+            Quaternion::from_axis_angle(&(0.0, 1.0, 0.0).into(), angle)
+        );
+        assert_eq!(
+            Quaternion::from_rotation_z(angle),
+            Quaternion::from_axis_angle(&(0.0, 0.0, 1.0).into(), angle)
+        );
+    }
+
+    #[test]
+    fn test_from_rotation_arc() {
+        let pairs: [(Vector3, Vector3); 4] = [
+            ((1.0, 0.0, 0.0).into(), (0.0, 1.0, 0.0).into()),
+            ((0.0, 0.6, 0.8).into(), (0.0, 0.6, 0.8).into()),
+            ((0.0, 0.0, 1.0).into(), (0.0, 0.0, -1.0).into()),
+            ((0.48, 0.6, 0.64).into(), (-0.48, -0.6, -0.64).into()),
+        ];
+
+        for (from, to) in pairs {
+            let q = Quaternion::from_rotation_arc(&from, &to);
+            let m = Matrix3::from_matrix4(&Matrix4::from_quaternion(&q));
+            let actual = m * from;
+
+            assert_float_absolute_eq!(q.norm(), 1.0);
+            assert_float_absolute_eq!(actual.x, to.x);
+            assert_float_absolute_eq!(actual.y, to.y);
+            assert_float_absolute_eq!(actual.z, to.z);
+        }
+    }
+
+    #[test]
+    fn test_look_rotation() {
+        let up = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+
+        let q = Quaternion::look_rotation(&(0.0, 0.0, -1.0).into(), &up);
+        assert_float_absolute_eq!(q.angle_to(&Quaternion::default()), 0.0);
+
+        let forwards: [Vector3; 4] = [
+            (1.0, 0.0, 0.0).into(),
+            (0.0, 0.0, 2.0).into(),
+            (1.0, 2.0, -3.0).into(),
+            (0.0, -1.0, 0.0).into(),
+        ];
+
+        for forward in forwards {
+            let q = Quaternion::look_rotation(&forward, &up);
+            let m = Matrix3::from_matrix4(&Matrix4::from_quaternion(&q));
+
+            let actual_forward = m * Vector3::from((0.0, 0.0, -1.0));
+            let expected_forward = forward.normalized();
+            let actual_up = m * up;
+
+            assert_float_absolute_eq!(actual_forward.x, expected_forward.x);
+            assert_float_absolute_eq!(actual_forward.y, expected_forward.y);
+            assert_float_absolute_eq!(actual_forward.z, expected_forward.z);
+            assert_float_absolute_eq!(actual_forward.dot(&actual_up), 0.0);
+            assert!(actual_up.y >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_to_mat3_padded() {
+        let q = Quaternion::from_axis_angle(&Vector3::from((1.0, 2.0, 3.0)).normalized(), 1.2);
+        let m = Matrix4::from_quaternion(&q);
+        let padded = q.to_mat3_padded();
+
+        for column in 0..3 {
+            for row in 0..3 {
+                assert_float_absolute_eq!(padded[column * 4 + row], m.elements[column * 4 + row]);
+            }
+
+            assert_eq!(padded[column * 4 + 3], 0.0);
+        }
+    }
+
+    #[test]
+    fn test_from_rotation_matrix() {
+        let axes: [Vector3; 4] = [
+            (1.0, 0.0, 0.0).into(),
+            (0.0, 1.0, 0.0).into(),
+            (0.0, 0.0, 1.0).into(),
+            (0.48, 0.6, 0.64).into(),
+        ];
+
+        for axis in axes {
+            for angle in [0.0, 1.0, 3.0, -2.5] {
+                let q = Quaternion::from_axis_angle(&axis, angle);
+                let m = Matrix3::from_rodrigues(&(axis * angle));
+
+                let actual = Quaternion::from_rotation_matrix(&m);
+                let sign = if actual.w * q.w < 0.0 { -1.0 } else { 1.0 };
+
+                assert_float_absolute_eq!(actual.x * sign, q.x);
+                assert_float_absolute_eq!(actual.y * sign, q.y);
+                assert_float_absolute_eq!(actual.z * sign, q.z);
+                assert_float_absolute_eq!(actual.w * sign, q.w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_super_fibonacci() {
+        assert!(Quaternion::super_fibonacci(0).is_empty());
+
+        let samples = Quaternion::super_fibonacci(72);
+        assert_eq!(samples.len(), 72);
+
+        for q in &samples {
+            assert_float_absolute_eq!(q.norm(), 1.0);
+        }
+
+        // No two samples should represent (nearly) the same rotation.
+        for (i, a) in samples.iter().enumerate() {
+            for b in &samples[i + 1..] {
+                let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+                assert!(dot.abs() < 0.99);
+            }
+        }
+    }
+
+    #[test]
+    fn test_velocity_from_samples() {
+        let axis = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+
+        // Rotating at 2 rad/s around Y, with one sample stored negated.
+        let samples = [
+            (0.0, Quaternion::from_axis_angle(&axis, 0.0)),
+            (0.1, Quaternion::from_axis_angle(&axis, 0.2)),
+            (
+                0.3,
+                Quaternion::from_axis_angle(&axis, 0.6 - 2.0 * f32::consts::PI),
+            ),
+            (0.4, Quaternion::from_axis_angle(&axis, 0.8)),
+        ];
+
+        let velocity = Quaternion::velocity_from_samples(&samples);
+
+        assert_float_absolute_eq!(velocity.x, 0.0);
+        assert_float_absolute_eq!(velocity.y, 2.0, 1e-4);
+        assert_float_absolute_eq!(velocity.z, 0.0);
+
+        assert_eq!(
+            Quaternion::velocity_from_samples(&samples[..1]),
+            Vector3::default()
+        );
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let q = Quaternion::from_rotation_x(0.3);
+
+        assert_eq!(Quaternion::default().to_string(), "(0, 0, 0, 1)");
+        assert_eq!(q.to_string().parse(), Ok(q));
+        assert!("(0, 0, 1)".parse::<Quaternion>().is_err());
+    }
+
+    #[test]
+    fn test_product() {
+        let rotations = [
+            Quaternion::from_rotation_x(0.5),
+            Quaternion::from_rotation_y(-0.3),
+            Quaternion::from_rotation_z(1.2),
+        ];
+
+        assert_eq!(
+            rotations.iter().product::<Quaternion>(),
+            rotations[0] * rotations[1] * rotations[2]
+        );
+        assert_eq!(
+            rotations.into_iter().product::<Quaternion>(),
+            rotations[0] * rotations[1] * rotations[2]
+        );
+        assert_eq!(
+            std::iter::empty::<Quaternion>().product::<Quaternion>(),
+            Quaternion::default()
+        );
+    }
+
     #[test]
     fn test_set() {
         let mut a = Quaternion::default();
@@ -256,6 +1186,28 @@ mod tests {
         assert_eq!(a.w, 4.0);
     }
 
+    #[test]
+    fn test_try_from_iter() {
+        let mut floats = [1.0, 2.0, 3.0, 4.0, 5.0].into_iter();
+
+        assert_eq!(
+            Quaternion::try_from_iter(&mut floats),
+            Ok(Quaternion {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                w: 4.0
+            })
+        );
+        assert_eq!(
+            Quaternion::try_from_iter(&mut floats),
+            Err(NotEnoughElementsError {
+                expected: 4,
+                found: 1
+            })
+        );
+    }
+
     #[test]
     fn test_norm_and_normalize() {
         let mut a = Quaternion {
@@ -270,6 +1222,308 @@ mod tests {
         assert_float_absolute_eq!(a.norm(), 1.0);
     }
 
+    #[test]
+    fn test_normalized() {
+        let mut a = Quaternion {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        };
+
+        assert!(!a.is_normalized(1e-6));
+
+        let b = a.normalized();
+        a.normalize();
+
+        assert_eq!(a, b);
+        assert!(b.is_normalized(1e-6));
+    }
+
+    #[test]
+    fn test_nlerp() {
+        let a = Quaternion::default();
+        let b = Quaternion::from_axis_angle(&(0.0, 0.0, 1.0).into(), f32::consts::FRAC_PI_2);
+
+        assert_eq!(Quaternion::nlerp(&a, &b, 0.0), a);
+
+        let end = Quaternion::nlerp(&a, &b, 1.0);
+        assert_float_absolute_eq!(end.z, b.z);
+        assert_float_absolute_eq!(end.w, b.w);
+
+        let half = Quaternion::nlerp(&a, &b, 0.5);
+        let expected = Quaternion::from_axis_angle(&(0.0, 0.0, 1.0).into(), f32::consts::FRAC_PI_4);
+        assert_float_absolute_eq!(half.norm(), 1.0);
+        assert_float_absolute_eq!(half.z, expected.z);
+        assert_float_absolute_eq!(half.w, expected.w);
+
+        // Interpolating towards the negated quaternion takes the short path.
+        let negated = Quaternion {
+            x: -b.x,
+            y: -b.y,
+            z: -b.z,
+            w: -b.w,
+        };
+        let half = Quaternion::nlerp(&a, &negated, 0.5);
+        assert_float_absolute_eq!(half.z, expected.z);
+        assert_float_absolute_eq!(half.w, expected.w);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let axis = Vector3 {
+            x: 0.0,
+            y: 0.6,
+            z: 0.8,
+        };
+        let a = Quaternion::from_axis_angle(&axis, 0.2);
+        let b = Quaternion::from_axis_angle(&axis, 2.2);
+
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            let actual = Quaternion::slerp(&a, &b, t);
+            let expected = Quaternion::from_axis_angle(&axis, 0.2 + 2.0 * t);
+
+            assert_float_absolute_eq!(actual.x, expected.x);
+            assert_float_absolute_eq!(actual.y, expected.y);
+            assert_float_absolute_eq!(actual.z, expected.z);
+            assert_float_absolute_eq!(actual.w, expected.w);
+        }
+
+        // Takes the short path to the negated quaternion.
+        let actual = Quaternion::slerp(&a, &-b, 0.5);
+        let expected = Quaternion::from_axis_angle(&axis, 1.2);
+        let sign = actual.w.signum() * expected.w.signum();
+
+        assert_float_absolute_eq!(actual.y * sign, expected.y);
+        assert_float_absolute_eq!(actual.w * sign, expected.w);
+    }
+
+    #[test]
+    fn test_log_and_exp() {
+        let q = Quaternion::from_axis_angle(&(0.0, 0.6, 0.8).into(), 1.2);
+        let log = q.log();
+
+        assert_float_absolute_eq!(log.length(), 0.6);
+
+        let actual = Quaternion::exp(&log);
+
+        assert_float_absolute_eq!(actual.x, q.x);
+        assert_float_absolute_eq!(actual.y, q.y);
+        assert_float_absolute_eq!(actual.z, q.z);
+        assert_float_absolute_eq!(actual.w, q.w);
+    }
+
+    #[test]
+    fn test_slerp_slice() {
+        let a = [
+            Quaternion::from_rotation_x(0.2),
+            Quaternion::from_rotation_y(-1.0),
+            Quaternion::default(),
+        ];
+        let b = [
+            Quaternion::from_rotation_x(1.4),
+            Quaternion::from_rotation_z(2.0),
+            Quaternion::from_rotation_y(0.5),
+        ];
+        let mut out = [Quaternion::default(); 3];
+
+        Quaternion::slerp_slice(&a, &b, 0.3, &mut out);
+
+        for i in 0..3 {
+            assert_eq!(out[i], Quaternion::slerp(&a[i], &b[i], 0.3));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slerp_slice_length_mismatch() {
+        let mut out = [Quaternion::default(); 2];
+
+        Quaternion::slerp_slice(
+            &[Quaternion::default(); 2],
+            &[Quaternion::default()],
+            0.5,
+            &mut out,
+        );
+    }
+
+    #[test]
+    fn test_squad() {
+        let axis = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        let keyframes = [0.0, 0.5, 1.0, 1.5].map(|angle| Quaternion::from_axis_angle(&axis, angle));
+
+        // Evenly spaced rotations around one axis have trivial control points.
+        let a = Quaternion::squad_control_point(&keyframes[0], &keyframes[1], &keyframes[2]);
+        let b = Quaternion::squad_control_point(&keyframes[1], &keyframes[2], &keyframes[3]);
+
+        assert_float_absolute_eq!(a.angle_to(&keyframes[1]), 0.0, 1e-5);
+        assert_float_absolute_eq!(b.angle_to(&keyframes[2]), 0.0, 1e-5);
+
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            let actual = Quaternion::squad(&keyframes[1], &a, &b, &keyframes[2], t);
+            let expected = Quaternion::from_axis_angle(&axis, 0.5 + 0.5 * t);
+
+            assert_float_absolute_eq!(actual.angle_to(&expected), 0.0, 1e-5);
+        }
+
+        // The curve passes through the keyframes for any control points.
+        let a = Quaternion::from_axis_angle(&(1.0, 0.0, 0.0).into(), 0.3);
+        let start = Quaternion::squad(&keyframes[0], &a, &b, &keyframes[3], 0.0);
+        let end = Quaternion::squad(&keyframes[0], &a, &b, &keyframes[3], 1.0);
+
+        assert_float_absolute_eq!(start.angle_to(&keyframes[0]), 0.0, 1e-5);
+        assert_float_absolute_eq!(end.angle_to(&keyframes[3]), 0.0, 1e-5);
+    }
+
+    #[test]
+    fn test_angle_to_and_rotate_towards() {
+        let axis = Vector3 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let a = Quaternion::from_axis_angle(&axis, 0.5);
+        let b = Quaternion::from_axis_angle(&axis, 2.0);
+
+        assert_float_absolute_eq!(a.angle_to(&b), 1.5, 1e-5);
+        assert_float_absolute_eq!(b.angle_to(&a), 1.5, 1e-5);
+        assert_float_absolute_eq!(a.angle_to(&a), 0.0);
+
+        let c = a.rotate_towards(&b, 0.5);
+        assert_float_absolute_eq!(a.angle_to(&c), 0.5, 1e-5);
+        assert_float_absolute_eq!(c.angle_to(&b), 1.0, 1e-5);
+
+        assert_eq!(a.rotate_towards(&b, 2.0), b);
+        assert_eq!(a.rotate_towards(&b, 0.0), a);
+    }
+
+    #[test]
+    fn test_swing_twist() {
+        let axis = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+
+        let twist = Quaternion::from_axis_angle(&axis, 0.7);
+        let swing = Quaternion::from_axis_angle(&(0.6, 0.0, 0.8).into(), 1.1);
+        let q = swing * twist;
+
+        let (actual_swing, actual_twist) = q.swing_twist(&axis);
+
+        assert_float_absolute_eq!(actual_swing.angle_to(&swing), 0.0);
+        assert_float_absolute_eq!(actual_twist.angle_to(&twist), 0.0);
+        assert_float_absolute_eq!((actual_swing * actual_twist).angle_to(&q), 0.0);
+    }
+
+    #[test]
+    fn test_constrained() {
+        let x = Quaternion::from_axis_angle(&(1.0, 0.0, 0.0).into(), 0.3);
+        let y = Quaternion::from_axis_angle(&(0.0, 1.0, 0.0).into(), 0.5);
+        let q = x * y;
+
+        assert_eq!(q.constrained([false, false, false]), Quaternion::default());
+        assert_eq!(q.constrained([true, true, true]), q);
+
+        let only_y = q.constrained([false, true, false]);
+        assert_float_absolute_eq!(only_y.angle_to(&y), 0.0);
+
+        let no_y = q.constrained([true, false, true]);
+        assert_float_absolute_eq!(no_y.angle_to(&x), 0.0);
+    }
+
+    #[test]
+    fn test_component_wise_operators() {
+        let mut a = Quaternion {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        };
+        let b = Quaternion {
+            x: 0.5,
+            y: -1.0,
+            z: 2.0,
+            w: 0.0,
+        };
+
+        let expected = Quaternion {
+            x: 1.5,
+            y: 1.0,
+            z: 5.0,
+            w: 4.0,
+        };
+        assert_eq!(a + b, expected);
+
+        let expected = Quaternion {
+            x: 2.0,
+            y: 4.0,
+            z: 6.0,
+            w: 8.0,
+        };
+        assert_eq!(a * 2.0, expected);
+        assert_eq!(2.0 * a, expected);
+
+        let expected = Quaternion {
+            x: -1.0,
+            y: -2.0,
+            z: -3.0,
+            w: -4.0,
+        };
+        assert_eq!(-a, expected);
+
+        a += b;
+        a *= 2.0;
+
+        let expected = Quaternion {
+            x: 3.0,
+            y: 2.0,
+            z: 10.0,
+            w: 8.0,
+        };
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn test_canonicalized() {
+        let a = Quaternion {
+            x: 0.1,
+            y: -0.2,
+            z: 0.3,
+            w: -0.9,
+        };
+
+        assert_eq!(a.canonicalized(), -a);
+        assert_eq!((-a).canonicalized(), -a);
+
+        let b = Quaternion {
+            x: 0.0,
+            y: -1.0,
+            z: 0.0,
+            w: 0.0,
+        };
+
+        assert_eq!(b.canonicalized(), -b);
+        assert_eq!((-b).canonicalized(), -b);
+    }
+
+    #[test]
+    fn test_debug_axis_angle() {
+        let q = Quaternion::from_rotation_z(f32::consts::FRAC_PI_2);
+
+        assert_eq!(
+            q.debug_axis_angle(),
+            format!(
+                "Quaternion {{ x: 0.0, y: 0.0, z: {:?}, w: {:?}, axis: (0.0, 0.0, 1.0), angle: 90.0° }}",
+                q.z, q.w
+            )
+        );
+    }
+
     #[test]
     fn test_conjugate() {
         let a = Quaternion {
@@ -288,6 +1542,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_invert() {
         let a = Quaternion {
             x: 1.0,
@@ -304,4 +1559,46 @@ mod tests {
         assert_eq!(a.z, -b.z);
         assert_eq!(a.w, b.w);
     }
+
+    #[test]
+    fn test_identity() {
+        let v = Vector3::from((1.0, 2.0, 3.0));
+
+        assert_eq!(Quaternion::default(), Quaternion::IDENTITY);
+        assert_eq!(
+            Quaternion::IDENTITY * Quaternion::from_rotation_x(0.5),
+            Quaternion::from_rotation_x(0.5)
+        );
+        assert_eq!(
+            Matrix3::from_matrix4(&Matrix4::from_quaternion(&Quaternion::IDENTITY)) * v,
+            v
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "wasm")]
+    fn test_to_js_array() {
+        let q = Quaternion {
+            x: 0.5,
+            y: -0.5,
+            z: 0.25,
+            w: 0.75,
+        };
+
+        assert_eq!(q.to_js_array(), [0.5, -0.5, 0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_6d() {
+        let q = Quaternion::from_axis_angle(&Vector3::from((1.0, -2.0, 0.5)).normalized(), 2.0);
+        let v = q.to_6d();
+
+        assert_float_absolute_eq!(Vector3::from([v[0], v[1], v[2]]).length(), 1.0, 1e-6);
+        assert!(Quaternion::from_6d(&v).angle_to(&q) < 1e-3);
+
+        // Unnormalized, non-orthogonal columns are orthonormalized.
+        let v = [2.0, 0.0, 0.0, 1.0, 3.0, 0.0];
+
+        assert!(Quaternion::from_6d(&v).angle_to(&Quaternion::IDENTITY) < 1e-3);
+    }
 }
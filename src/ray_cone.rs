@@ -0,0 +1,121 @@
+/// Cone around a ray that tracks the footprint of a pixel as the ray travels,
+/// e.g. to select texture mip levels on the CPU the same way the GPU does.
+///
+/// The implementation is based on [Akenine-Möller et al., "Texture Level of
+/// Detail Strategies for Real-Time Ray Tracing"][raycones], Ray Tracing Gems
+/// (2019).
+///
+/// [raycones]: https://link.springer.com/chapter/10.1007/978-1-4842-4427-2_20
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RayCone {
+    /// The width of the cone at the ray origin.
+    pub width: f32,
+    /// The full opening angle of the cone in radians.
+    pub spread_angle: f32,
+}
+
+unsafe impl Send for RayCone {}
+unsafe impl Sync for RayCone {}
+
+impl RayCone {
+    /// Creates a new ray cone with the given width at the ray origin and full
+    /// opening angle in radians.
+    pub fn new(width: f32, spread_angle: f32) -> Self {
+        Self {
+            width,
+            spread_angle,
+        }
+    }
+
+    /// Creates the ray cone of a primary ray through one pixel of a
+    /// perspective camera with the given vertical field of view in radians
+    /// and image height in pixels.
+    pub fn from_camera(fov_y: f32, image_height: u32) -> Self {
+        Self {
+            width: 0.0,
+            spread_angle: (2.0 * (fov_y / 2.0).tan() / image_height as f32).atan(),
+        }
+    }
+
+    /// Returns the width of the cone at the given distance along the ray.
+    pub fn spread_at_distance(&self, distance: f32) -> f32 {
+        self.width + 2.0 * distance * (self.spread_angle / 2.0).tan()
+    }
+
+    /// Returns the cone that continues from the given distance along the ray,
+    /// e.g. for a reflected ray. `surface_spread_angle` is the additional
+    /// spread caused by the curvature of the surface that was hit.
+    pub fn propagate(&self, distance: f32, surface_spread_angle: f32) -> Self {
+        Self {
+            width: self.spread_at_distance(distance),
+            spread_angle: self.spread_angle + surface_spread_angle,
+        }
+    }
+
+    /// Returns the texture level of detail (mip level) at a hit at the given
+    /// distance along the ray.
+    ///
+    /// `cos_incidence` is the absolute cosine of the angle between the ray
+    /// direction and the surface normal. `lod_bias` is the level of detail of
+    /// the triangle that was hit, see [`Self::triangle_lod_bias`].
+    pub fn texture_lod(&self, distance: f32, cos_incidence: f32, lod_bias: f32) -> f32 {
+        lod_bias
+            + (self.spread_at_distance(distance) / cos_incidence)
+                .abs()
+                .log2()
+    }
+
+    /// Returns the level of detail of a triangle for [`Self::texture_lod`],
+    /// given the area of the triangle in texels (i.e. its UV area multiplied
+    /// by the texture width and height) and its area in world space.
+    pub fn triangle_lod_bias(texel_area: f32, world_area: f32) -> f32 {
+        0.5 * (texel_area / world_area).log2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_spread_at_distance() {
+        // A 90° field of view spans 2 units at distance 1.
+        let cone = RayCone::from_camera(FRAC_PI_2, 100);
+
+        assert_float_absolute_eq!(cone.spread_at_distance(0.0), 0.0);
+        assert_float_absolute_eq!(cone.spread_at_distance(1.0), 0.02, 1e-5);
+        assert_float_absolute_eq!(cone.spread_at_distance(10.0), 0.2, 1e-4);
+
+        let cone = RayCone::new(1.0, FRAC_PI_2);
+        assert_float_absolute_eq!(cone.spread_at_distance(2.0), 5.0, 1e-5);
+    }
+
+    #[test]
+    fn test_propagate() {
+        let cone = RayCone::new(0.0, 0.1).propagate(10.0, 0.05);
+
+        assert_float_absolute_eq!(cone.width, 20.0 * 0.05f32.tan());
+        assert_float_absolute_eq!(cone.spread_angle, 0.15);
+    }
+
+    #[test]
+    fn test_texture_lod() {
+        // One texel per world unit, seen head-on with a footprint of 4 units.
+        let cone = RayCone::new(4.0, 0.0);
+        let bias = RayCone::triangle_lod_bias(1.0, 1.0);
+
+        assert_float_absolute_eq!(bias, 0.0);
+        assert_float_absolute_eq!(cone.texture_lod(5.0, 1.0, bias), 2.0);
+
+        // Grazing angles stretch the footprint.
+        assert_float_absolute_eq!(cone.texture_lod(5.0, 0.5, bias), 3.0);
+
+        // Four times as many texels per unit area doubles the resolution.
+        let bias = RayCone::triangle_lod_bias(4.0, 1.0);
+        assert_float_absolute_eq!(cone.texture_lod(5.0, 1.0, bias), 3.0);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::linalg::wrap_angle;
+
+use super::{AxisAngle, Quaternion, Vector3};
+
+/// Damped spring that smoothly animates a scalar value towards a target, e.g.
+/// a number on a HUD.
+///
+/// The spring is integrated implicitly, so it stays stable for any time step.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Spring {
+    /// The current value.
+    pub value: f32,
+    /// The current rate of change of the value per second.
+    pub velocity: f32,
+    /// How strongly the value is pulled towards the target.
+    pub stiffness: f32,
+    /// How strongly the velocity is damped.
+    pub damping: f32,
+}
+
+unsafe impl Send for Spring {}
+unsafe impl Sync for Spring {}
+
+impl Spring {
+    /// Creates a new spring at rest at the given value.
+    pub fn new(value: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.0,
+            stiffness,
+            damping,
+        }
+    }
+
+    /// Creates a new critically damped spring at rest at the given value. A
+    /// critically damped spring reaches the target as fast as possible without
+    /// overshooting.
+    pub fn critically_damped(value: f32, stiffness: f32) -> Self {
+        Self::new(value, stiffness, 2.0 * stiffness.sqrt())
+    }
+
+    /// Advances the spring towards the given target by the given time step in
+    /// seconds, and returns the new value.
+    pub fn update(&mut self, target: f32, dt: f32) -> f32 {
+        self.velocity = (self.velocity + dt * self.stiffness * (target - self.value))
+            / (1.0 + dt * self.damping + dt * dt * self.stiffness);
+        self.value += dt * self.velocity;
+
+        self.value
+    }
+}
+
+/// Damped spring that smoothly animates an angle in radians towards a target
+/// angle, always turning the short way around, e.g. a needle on a dial.
+///
+/// The angle is kept between `-π` and `π`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AngleSpring {
+    /// The current angle in radians.
+    pub value: f32,
+    /// The current angular velocity in radians per second.
+    pub velocity: f32,
+    /// How strongly the angle is pulled towards the target.
+    pub stiffness: f32,
+    /// How strongly the angular velocity is damped.
+    pub damping: f32,
+}
+
+unsafe impl Send for AngleSpring {}
+unsafe impl Sync for AngleSpring {}
+
+impl AngleSpring {
+    /// Creates a new angle spring at rest at the given angle in radians.
+    pub fn new(value: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            value: wrap_angle(value),
+            velocity: 0.0,
+            stiffness,
+            damping,
+        }
+    }
+
+    /// Creates a new critically damped angle spring at rest at the given angle
+    /// in radians.
+    pub fn critically_damped(value: f32, stiffness: f32) -> Self {
+        Self::new(value, stiffness, 2.0 * stiffness.sqrt())
+    }
+
+    /// Advances the spring towards the given target angle in radians by the
+    /// given time step in seconds, and returns the new angle.
+    pub fn update(&mut self, target: f32, dt: f32) -> f32 {
+        let mut spring = Spring {
+            value: self.value,
+            velocity: self.velocity,
+            stiffness: self.stiffness,
+            damping: self.damping,
+        };
+
+        spring.update(self.value + wrap_angle(target - self.value), dt);
+
+        self.value = wrap_angle(spring.value);
+        self.velocity = spring.velocity;
+
+        self.value
+    }
+}
+
+/// Damped spring that smoothly turns a rotation to aim at a moving target,
+/// e.g. a camera or turret tracking an object.
+///
+/// Each update aims the rotation with [`Quaternion::look_rotation`], so its
+/// -Z axis faces the target, and pulls it towards that aim along the shortest
+/// arc. Like [`Spring`], it is integrated implicitly, so it stays stable for
+/// any time step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothedLookAt {
+    /// The current rotation.
+    pub rotation: Quaternion,
+    /// The current angular velocity, as an axis scaled by the speed in
+    /// radians per second.
+    pub angular_velocity: Vector3,
+    /// The up direction to keep the rotation upright with.
+    pub up: Vector3,
+    /// How strongly the rotation is pulled towards the aim.
+    pub stiffness: f32,
+    /// How strongly the angular velocity is damped.
+    pub damping: f32,
+}
+
+unsafe impl Send for SmoothedLookAt {}
+unsafe impl Sync for SmoothedLookAt {}
+
+impl SmoothedLookAt {
+    /// Creates a new look-at spring at rest at the given rotation, kept
+    /// upright with the +Y axis.
+    pub fn new(rotation: Quaternion, stiffness: f32, damping: f32) -> Self {
+        Self {
+            rotation,
+            angular_velocity: Vector3::default(),
+            up: (0.0, 1.0, 0.0).into(),
+            stiffness,
+            damping,
+        }
+    }
+
+    /// Creates a new critically damped look-at spring at rest at the given
+    /// rotation, which turns to the aim as fast as possible without
+    /// overshooting.
+    pub fn critically_damped(rotation: Quaternion, stiffness: f32) -> Self {
+        Self::new(rotation, stiffness, 2.0 * stiffness.sqrt())
+    }
+
+    /// Advances the rotation towards aiming from `eye` at `target` by the
+    /// given time step in seconds, and returns the new rotation.
+    ///
+    /// If `eye` and `target` coincide, there is nothing to aim at, so the
+    /// rotation only keeps turning with its damped velocity.
+    pub fn update(&mut self, eye: &Vector3, target: &Vector3, dt: f32) -> Quaternion {
+        let forward = target - eye;
+        let error = if forward.length() == 0.0 {
+            Vector3::default()
+        } else {
+            let aim = Quaternion::look_rotation(&forward, &self.up);
+
+            AxisAngle::from(&(aim * self.rotation.conjugate())).to_rotation_vector()
+        };
+
+        self.angular_velocity = (self.angular_velocity + error * (dt * self.stiffness))
+            / (1.0 + dt * self.damping + dt * dt * self.stiffness);
+
+        let step = AxisAngle::from_rotation_vector(&(self.angular_velocity * dt));
+        self.rotation = (Quaternion::from(&step) * self.rotation).normalized();
+
+        self.rotation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_float_eq::assert_float_absolute_eq;
+
+    use super::*;
+    use crate::{Matrix3, Matrix4};
+
+    #[test]
+    fn test_spring() {
+        let mut spring = Spring::critically_damped(0.0, 100.0);
+        let mut previous = spring.value;
+
+        for _ in 0..120 {
+            let value = spring.update(10.0, 1.0 / 60.0);
+
+            // Critically damped springs do not overshoot.
+            assert!(value >= previous && value <= 10.0);
+            previous = value;
+        }
+
+        assert_float_absolute_eq!(spring.value, 10.0, 1e-3);
+
+        // Large time steps stay stable.
+        let mut spring = Spring::new(0.0, 1000.0, 1.0);
+        spring.update(1.0, 10.0);
+        assert!(spring.value.abs() <= 1.0);
+    }
+
+    #[test]
+    fn test_angle_spring() {
+        let mut spring = AngleSpring::critically_damped(3.0, 100.0);
+
+        // The short way from 3 to -3 goes through π.
+        let value = spring.update(-3.0, 1.0 / 60.0);
+        assert!(!(-3.0..=3.0).contains(&value));
+
+        for _ in 0..120 {
+            spring.update(-3.0, 1.0 / 60.0);
+        }
+
+        assert_float_absolute_eq!(spring.value, -3.0, 1e-3);
+    }
+
+    #[test]
+    fn test_smoothed_look_at() {
+        let mut look_at = SmoothedLookAt::critically_damped(Quaternion::default(), 100.0);
+        let eye = Vector3::from((0.0, 1.0, 0.0));
+        let target = Vector3::from((5.0, 1.0, 0.0));
+        let aim = Quaternion::look_rotation(&(target - eye), &(0.0, 1.0, 0.0).into());
+
+        let mut previous = look_at.rotation.angle_to(&aim);
+
+        for _ in 0..120 {
+            let angle = look_at.update(&eye, &target, 1.0 / 60.0).angle_to(&aim);
+
+            // Critically damped springs do not overshoot.
+            assert!(angle <= previous + 1e-5);
+            previous = angle;
+        }
+
+        assert!(previous < 1e-3);
+
+        // The -Z axis faces the target.
+        let forward = Matrix3::from_matrix4(&Matrix4::from_quaternion(&look_at.rotation))
+            * Vector3::from((0.0, 0.0, -1.0));
+
+        assert_float_absolute_eq!(forward.x, 1.0, 1e-3);
+
+        // Nothing to aim at leaves a rotation at rest unchanged.
+        let rotation = look_at.rotation;
+        look_at.angular_velocity = Vector3::default();
+
+        assert_eq!(look_at.update(&eye, &eye, 1.0 / 60.0), rotation);
+    }
+}
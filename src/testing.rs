@@ -0,0 +1,120 @@
+//! Assertions for invariants of rotations and transformations, for testing
+//! code that manipulates the types of this library.
+//!
+//! This module is only available with the `testing` feature.
+
+use super::{Basis, Matrix3, Matrix4, Quaternion, Vector3};
+
+/// Asserts that rotating `v` by the rotation quaternion `q` preserves its
+/// length within `epsilon`, which holds if and only if `q` is normalized.
+///
+/// # Panics
+///
+/// Panics if the lengths differ by more than `epsilon`.
+#[track_caller]
+pub fn assert_rotation_preserves_length(q: &Quaternion, v: &Vector3, epsilon: f32) {
+    let rotated = Matrix3::from_matrix4(&Matrix4::from_quaternion(q)) * v;
+    let (before, after) = (v.length(), rotated.length());
+
+    assert!(
+        (before - after).abs() <= epsilon,
+        "rotation by {q} changed the length of {v} from {before} to {after}"
+    );
+}
+
+/// Asserts that the columns of `m` are unit vectors perpendicular to each
+/// other within `epsilon`, i.e. that `m` is a rotation or a reflection.
+///
+/// # Panics
+///
+/// Panics if `m` is not orthonormal.
+#[track_caller]
+pub fn assert_orthonormal(m: &Matrix3, epsilon: f32) {
+    let basis = Basis {
+        x: m[0],
+        y: m[1],
+        z: m[2],
+    };
+
+    assert!(
+        basis.is_orthonormal(epsilon),
+        "matrix with columns {}, {}, and {} is not orthonormal",
+        m[0],
+        m[1],
+        m[2]
+    );
+}
+
+/// Asserts that multiplying `m` by its inverse gives the identity matrix
+/// within `epsilon` per element.
+///
+/// # Panics
+///
+/// Panics if `m` is singular or its inverse is inaccurate.
+#[track_caller]
+pub fn assert_inverse_roundtrip(m: &Matrix4, epsilon: f32) {
+    let difference = (m * m.inverse()).max_component_difference(&Matrix4::identity());
+
+    assert!(
+        difference <= epsilon,
+        "matrix times its inverse differs from the identity by {difference}: {m:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assert_rotation_preserves_length() {
+        let q = Quaternion::from_rotation_y(0.7);
+
+        assert_rotation_preserves_length(&q, &(1.0, 2.0, 3.0).into(), 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_rotation_preserves_length_unnormalized() {
+        let q = Quaternion::from_rotation_y(0.7) * 1.1;
+
+        assert_rotation_preserves_length(&q, &(1.0, 2.0, 3.0).into(), 1e-5);
+    }
+
+    #[test]
+    fn test_assert_orthonormal() {
+        assert_orthonormal(&Matrix3::from_rodrigues(&(0.3, -0.2, 0.9).into()), 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_orthonormal_scaled() {
+        assert_orthonormal(&(Matrix3::identity() * 2.0), 1e-5);
+    }
+
+    #[test]
+    fn test_assert_inverse_roundtrip() {
+        let m = Matrix4::compose(
+            &(1.0, 2.0, 3.0).into(),
+            &Quaternion::from_rotation_x(0.4),
+            &(2.0, 0.5, 1.0).into(),
+        );
+
+        assert_inverse_roundtrip(&m, 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_inverse_roundtrip_singular() {
+        assert_inverse_roundtrip(&Matrix4::zero(), 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_inverse_roundtrip_nan() {
+        let m = Matrix4 {
+            elements: [f32::NAN; 16],
+        };
+
+        assert_inverse_roundtrip(&m, 1e-5);
+    }
+}
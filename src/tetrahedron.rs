@@ -0,0 +1,124 @@
+use super::{Vector3, Vector4};
+
+/// Returns the signed volume of the tetrahedron with the corners `a`, `b`,
+/// `c`, and `d`.
+///
+/// The volume is positive if `d` is on the side of the triangle `abc` from
+/// which `a`, `b`, and `c` appear counterclockwise, negative if it is on the
+/// other side, and zero if the corners are coplanar. This makes it an
+/// orientation predicate as well as a volume.
+pub fn signed_volume(a: &Vector3, b: &Vector3, c: &Vector3, d: &Vector3) -> f32 {
+    (b - a).cross(&(c - a)).dot(&(d - a)) / 6.0
+}
+
+/// Returns the barycentric coordinates of `p` in the tetrahedron with the
+/// corners `a`, `b`, `c`, and `d`, as the weights of `a`, `b`, `c`, and `d`
+/// in the x, y, z, and w components.
+///
+/// The coordinates sum to 1, and are all non-negative if and only if `p` is
+/// inside the tetrahedron, so they can be used to interpolate attributes
+/// stored at the corners, e.g. for FEM-style deformation.
+///
+/// Returns `None` if the tetrahedron is degenerate.
+pub fn tetrahedron_barycentric(
+    p: &Vector3,
+    a: &Vector3,
+    b: &Vector3,
+    c: &Vector3,
+    d: &Vector3,
+) -> Option<Vector4> {
+    let volume = signed_volume(a, b, c, d);
+
+    if volume == 0.0 {
+        return None;
+    }
+
+    // Each coordinate is the volume of the tetrahedron with that corner
+    // replaced by p, relative to the whole.
+    Some(Vector4 {
+        x: signed_volume(p, b, c, d) / volume,
+        y: signed_volume(a, p, c, d) / volume,
+        z: signed_volume(a, b, p, d) / volume,
+        w: signed_volume(a, b, c, p) / volume,
+    })
+}
+
+/// Returns whether `p` is inside or on the boundary of the tetrahedron with
+/// the corners `a`, `b`, `c`, and `d`, in either orientation. Degenerate
+/// tetrahedra contain no points.
+pub fn point_in_tetrahedron(
+    p: &Vector3,
+    a: &Vector3,
+    b: &Vector3,
+    c: &Vector3,
+    d: &Vector3,
+) -> bool {
+    tetrahedron_barycentric(p, a, b, c, d)
+        .is_some_and(|uvwt| uvwt.x >= 0.0 && uvwt.y >= 0.0 && uvwt.z >= 0.0 && uvwt.w >= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORNERS: [Vector3; 4] = [Vector3::ZERO, Vector3::X, Vector3::Y, Vector3::Z];
+
+    #[test]
+    fn test_signed_volume() {
+        let [a, b, c, d] = CORNERS;
+
+        assert_eq!(signed_volume(&a, &b, &c, &d), 1.0 / 6.0);
+        assert_eq!(signed_volume(&a, &c, &b, &d), -1.0 / 6.0);
+        assert_eq!(signed_volume(&a, &b, &c, &(2.0, 3.0, 0.0).into()), 0.0);
+    }
+
+    #[test]
+    fn test_tetrahedron_barycentric() {
+        let [a, b, c, d] = CORNERS;
+        let p = Vector3::from((0.1, 0.2, 0.3));
+
+        let uvwt = tetrahedron_barycentric(&p, &a, &b, &c, &d).unwrap();
+
+        assert!((uvwt.x - 0.4).abs() < 1e-6);
+        assert!((uvwt.y - 0.1).abs() < 1e-6);
+        assert!((uvwt.z - 0.2).abs() < 1e-6);
+        assert!((uvwt.w - 0.3).abs() < 1e-6);
+
+        assert_eq!(tetrahedron_barycentric(&p, &a, &b, &c, &(b + c)), None);
+    }
+
+    #[test]
+    fn test_point_in_tetrahedron() {
+        let [a, b, c, d] = CORNERS;
+
+        assert!(point_in_tetrahedron(
+            &(0.1, 0.2, 0.3).into(),
+            &a,
+            &b,
+            &c,
+            &d
+        ));
+        assert!(point_in_tetrahedron(
+            &(0.1, 0.2, 0.3).into(),
+            &a,
+            &c,
+            &b,
+            &d
+        ));
+        assert!(point_in_tetrahedron(&a, &a, &b, &c, &d));
+        assert!(!point_in_tetrahedron(
+            &(0.5, 0.5, 0.5).into(),
+            &a,
+            &b,
+            &c,
+            &d
+        ));
+        assert!(!point_in_tetrahedron(
+            &(-0.1, 0.2, 0.3).into(),
+            &a,
+            &b,
+            &c,
+            &d
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+use std::{fmt, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+
+use super::ParseError;
+
+/// 2D vector for quantities such as screen-space points and texture
+/// coordinates.
+///
+/// You can convert a tuple or an array of two floats to a 2D vector using
+/// `.into()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vector2 {
+    /// The x component.
+    pub x: f32,
+    /// The y component.
+    pub y: f32,
+}
+
+unsafe impl Send for Vector2 {}
+unsafe impl Sync for Vector2 {}
+
+impl Eq for Vector2 {}
+
+impl fmt::Display for Vector2 {
+    /// Formats this vector as `(x, y)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.x, self.y])
+    }
+}
+
+impl FromStr for Vector2 {
+    type Err = ParseError;
+
+    /// Parses a vector in the format written by `Display`, e.g. `(x, y)`. The
+    /// parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y] = parse_components::<f32, 2>(s)?;
+
+        Ok(Self { x, y })
+    }
+}
+
+impl From<(f32, f32)> for Vector2 {
+    fn from(tuple: (f32, f32)) -> Self {
+        Vector2 {
+            x: tuple.0,
+            y: tuple.1,
+        }
+    }
+}
+
+impl From<[f32; 2]> for Vector2 {
+    fn from(array: [f32; 2]) -> Self {
+        Vector2 {
+            x: array[0],
+            y: array[1],
+        }
+    }
+}
+
+impl Vector2 {
+    /// Sets the elements of this vector.
+    pub fn set(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        let expected = Vector2 { x: 1.0, y: 2.0 };
+
+        assert_eq!(Vector2::from((1.0, 2.0)), expected);
+        assert_eq!(Vector2::from([1.0, 2.0]), expected);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let v = Vector2 { x: 1.5, y: -2.0 };
+
+        assert_eq!(v.to_string(), "(1.5, -2)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    #[test]
+    fn test_set() {
+        let mut v = Vector2::default();
+
+        v.set(1.0, 2.0);
+
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+    }
+}
@@ -1,4 +1,20 @@
-use std::ops;
+use std::{f32::consts::TAU, fmt, iter, ops, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+use crate::linalg::sum_of_products;
+
+use super::{Matrix3, Matrix4, NotEnoughElementsError, ParseError, Vector2, Vector4};
+
+/// Coordinate axis in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+unsafe impl Send for Axis {}
+unsafe impl Sync for Axis {}
 
 /// 3D vector for quantities such as 3D points, 3D directions, etc.
 ///
@@ -23,6 +39,10 @@ use std::ops;
 ///
 /// You can use operators such as `+`, `-`, `*`, `/` for element-wise addition,
 /// subtraction, multiplication, division, and negation.
+///
+/// The components can also be accessed by index, e.g. `v[0]` for x, or by
+/// [`Axis`], e.g. `v[Axis::Y]` for y, using [`ops::Index`] and
+/// [`ops::IndexMut`].
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Vector3 {
@@ -39,6 +59,39 @@ unsafe impl Sync for Vector3 {}
 
 impl Eq for Vector3 {}
 
+impl fmt::Display for Vector3 {
+    /// Formats this vector as `(x, y, z)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.x, self.y, self.z])
+    }
+}
+
+impl FromStr for Vector3 {
+    type Err = ParseError;
+
+    /// Parses a vector in the format written by `Display`, e.g. `(x, y, z)`.
+    /// The parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse_components::<f32, 3>(s)?;
+
+        Ok(Self { x, y, z })
+    }
+}
+
+impl iter::Sum for Vector3 {
+    fn sum<I: Iterator<Item = Vector3>>(iter: I) -> Self {
+        iter.fold(Vector3::default(), |acc, v| acc + v)
+    }
+}
+
+impl<'a> iter::Sum<&'a Vector3> for Vector3 {
+    fn sum<I: Iterator<Item = &'a Vector3>>(iter: I) -> Self {
+        iter.fold(Vector3::default(), |acc, v| acc + v)
+    }
+}
+
 impl From<(f32, f32, f32)> for Vector3 {
     fn from(tuple: (f32, f32, f32)) -> Self {
         Vector3 {
@@ -59,6 +112,68 @@ impl From<[f32; 3]> for Vector3 {
     }
 }
 
+impl ops::Index<usize> for Vector3 {
+    type Output = f32;
+
+    /// Returns the component at the given index, where x, y, and z are at
+    /// indices 0, 1, and 2, respectively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 2.
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => {
+                panic!("index out of bounds: a 3D vector has 3 components but the index is {index}")
+            }
+        }
+    }
+}
+
+impl ops::IndexMut<usize> for Vector3 {
+    /// Returns the component at the given index, where x, y, and z are at
+    /// indices 0, 1, and 2, respectively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than 2.
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => {
+                panic!("index out of bounds: a 3D vector has 3 components but the index is {index}")
+            }
+        }
+    }
+}
+
+impl ops::Index<Axis> for Vector3 {
+    type Output = f32;
+
+    fn index(&self, axis: Axis) -> &f32 {
+        match axis {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+            Axis::Z => &self.z,
+        }
+    }
+}
+
+impl ops::IndexMut<Axis> for Vector3 {
+    fn index_mut(&mut self, axis: Axis) -> &mut f32 {
+        match axis {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+            Axis::Z => &mut self.z,
+        }
+    }
+}
+
 impl_op_ex!(+ |a: &Vector3, b: &Vector3| -> Vector3 {
     Vector3 {
         x: a.x + b.x,
@@ -180,6 +295,63 @@ impl_op_ex!(-|v: &Vector3| -> Vector3 {
 });
 
 impl Vector3 {
+    /// The zero vector.
+    pub const ZERO: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// The vector with all components set to 1.
+    pub const ONE: Self = Self {
+        x: 1.0,
+        y: 1.0,
+        z: 1.0,
+    };
+
+    /// The unit vector along the +X axis.
+    pub const X: Self = Self {
+        x: 1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// The unit vector along the +Y axis.
+    pub const Y: Self = Self {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+
+    /// The unit vector along the +Z axis.
+    pub const Z: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: 1.0,
+    };
+
+    /// The unit vector along the -X axis.
+    pub const NEG_X: Self = Self {
+        x: -1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// The unit vector along the -Y axis.
+    pub const NEG_Y: Self = Self {
+        x: 0.0,
+        y: -1.0,
+        z: 0.0,
+    };
+
+    /// The unit vector along the -Z axis, which is the forward direction of a
+    /// camera.
+    pub const NEG_Z: Self = Self {
+        x: 0.0,
+        y: 0.0,
+        z: -1.0,
+    };
+
     /// Sets the elements of this vector.
     pub fn set(&mut self, x: f32, y: f32, z: f32) {
         self.x = x;
@@ -187,12 +359,124 @@ impl Vector3 {
         self.z = z;
     }
 
+    /// Creates a new vector from the first three floats of the given
+    /// iterator, in x, y, z order. Any remaining floats are left in the
+    /// iterator, so passing `&mut iter` allows reading consecutive vectors
+    /// from a stream.
+    ///
+    /// Returns an error if the iterator yields fewer than three floats.
+    pub fn try_from_iter(
+        iter: impl IntoIterator<Item = f32>,
+    ) -> Result<Self, NotEnoughElementsError> {
+        let mut iter = iter.into_iter();
+        let mut next = |found| {
+            iter.next()
+                .ok_or(NotEnoughElementsError { expected: 3, found })
+        };
+
+        Ok(Self {
+            x: next(0)?,
+            y: next(1)?,
+            z: next(2)?,
+        })
+    }
+
+    /// Returns a vector with `f` applied to each component of this vector.
+    pub fn map(&self, mut f: impl FnMut(f32) -> f32) -> Self {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    /// Returns a vector with `f` applied to each pair of corresponding
+    /// components of this vector and `other`.
+    pub fn zip_with(&self, other: &Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Self {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+        }
+    }
+
+    /// Returns the component-wise minimum of this vector and another vector.
+    pub fn min(&self, other: &Self) -> Self {
+        self.zip_with(other, f32::min)
+    }
+
+    /// Returns the component-wise maximum of this vector and another vector.
+    pub fn max(&self, other: &Self) -> Self {
+        self.zip_with(other, f32::max)
+    }
+
+    /// Returns this vector with each component clamped between the matching
+    /// components of `min` and `max`. Like WGSL `clamp`, this does not panic
+    /// if a component of `min` is greater than the one of `max`.
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Returns the component-wise absolute value of this vector.
+    pub fn abs(&self) -> Self {
+        self.map(f32::abs)
+    }
+
+    /// Returns the component-wise sign of this vector, where each component
+    /// is `1.0`, `-1.0`, or `0.0`. Like WGSL `sign` and unlike
+    /// [`f32::signum`], zero components stay zero.
+    pub fn signum(&self) -> Self {
+        self.map(|x| if x == 0.0 { 0.0 } else { x.signum() })
+    }
+
+    /// Returns this vector with each component rounded down.
+    pub fn floor(&self) -> Self {
+        self.map(f32::floor)
+    }
+
+    /// Returns this vector with each component rounded up.
+    pub fn ceil(&self) -> Self {
+        self.map(f32::ceil)
+    }
+
+    /// Returns this vector with each component rounded to the nearest
+    /// integer, with halfway cases rounded away from zero.
+    pub fn round(&self) -> Self {
+        self.map(f32::round)
+    }
+
+    /// Returns the fractional part of each component of this vector, computed
+    /// as `x - x.floor()` like WGSL `fract`, so it is in `[0, 1]`, even for
+    /// negative components. It can be exactly `1.0` for tiny negative
+    /// components such as `-1e-9`, where `x - (-1.0)` rounds to `1.0`.
+    pub fn fract(&self) -> Self {
+        self.map(|x| x - x.floor())
+    }
+
     /// Returns the length of this vector.
     pub fn length(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the squared length of this vector. This is cheaper than
+    /// [`Self::length`], e.g. for comparing lengths.
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Returns the distance between this point and another point.
+    pub fn distance(&self, other: &Self) -> f32 {
+        self.distance_squared(other).sqrt()
     }
 
-    /// Normalizes this vector.
+    /// Returns the squared distance between this point and another point.
+    /// This is cheaper than [`Self::distance`], e.g. for comparing distances.
+    pub fn distance_squared(&self, other: &Self) -> f32 {
+        (self - other).length_squared()
+    }
+
+    /// Normalizes this vector. The components become NaN if the length is
+    /// zero; see [`Self::try_normalize`] for a checked alternative.
     pub fn normalize(&mut self) {
         let length = self.length();
 
@@ -201,7 +485,9 @@ impl Vector3 {
         self.z /= length;
     }
 
-    /// Returns the normalized version of this vector.
+    /// Returns the normalized version of this vector. The components are NaN
+    /// if the length is zero; see [`Self::try_normalize`] for a checked
+    /// alternative.
     pub fn normalized(&self) -> Self {
         let length = self.length();
 
@@ -212,27 +498,621 @@ impl Vector3 {
         }
     }
 
+    /// Returns the normalized version of this vector, or `fallback` if this
+    /// vector cannot be normalized, e.g. because its length is zero.
+    ///
+    /// Unlike [`Self::normalized`], this never produces NaN components.
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns the normalized version of this vector, or `None` if this vector
+    /// cannot be normalized, e.g. because its length is zero or infinite.
+    pub fn try_normalize(&self) -> Option<Self> {
+        let reciprocal = self.length().recip();
+
+        if reciprocal.is_finite() && reciprocal > 0.0 {
+            Some(self * reciprocal)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the normalized version of this vector, or the zero vector if
+    /// this vector cannot be normalized.
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::default())
+    }
+
+    /// Returns this vector with its direction kept and its length set to
+    /// `length`. The zero vector stays zero.
+    pub fn with_length(&self, length: f32) -> Self {
+        self.normalize_or_zero() * length
+    }
+
+    /// Returns this vector with its length clamped to at most `max`, e.g. to
+    /// limit a velocity to a top speed.
+    pub fn clamp_length(&self, max: f32) -> Self {
+        let length_squared = self.length_squared();
+
+        if length_squared > max * max {
+            self * (max / length_squared.sqrt())
+        } else {
+            *self
+        }
+    }
+
+    /// Returns this vector with its length clamped between `min` and `max`.
+    /// The zero vector stays zero, since it has no direction to scale along.
+    pub fn clamp_length_between(&self, min: f32, max: f32) -> Self {
+        let length = self.length();
+
+        if length > max {
+            self * (max / length)
+        } else if length < min && length > 0.0 {
+            self * (min / length)
+        } else {
+            *self
+        }
+    }
+
     /// Returns the dot product of this vector with another vector.
     pub fn dot(&self, rhs: &Self) -> f32 {
-        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+        sum_of_products([self.x, self.y, self.z], [rhs.x, rhs.y, rhs.z])
     }
 
     /// Returns the cross product of this vector with another vector.
     pub fn cross(&self, rhs: &Self) -> Self {
+        // Unlike the dot product, this is never fused: fusing one of the two
+        // products would break `a × b == -(b × a)` and `a × a == 0`.
         Self {
             x: self.y * rhs.z - self.z * rhs.y,
             y: self.z * rhs.x - self.x * rhs.z,
             z: self.x * rhs.y - self.y * rhs.x,
         }
     }
+
+    /// Returns the projection of this vector onto another vector, i.e. the
+    /// component of this vector parallel to `other`. Returns the zero vector
+    /// if `other` is the zero vector.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let length_squared = other.dot(other);
+
+        if length_squared == 0.0 {
+            Self::default()
+        } else {
+            other * (self.dot(other) / length_squared)
+        }
+    }
+
+    /// Returns the rejection of this vector from another vector, i.e. the
+    /// component of this vector perpendicular to `other`. This is this vector
+    /// itself if `other` is the zero vector.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        self - self.project_onto(other)
+    }
+
+    /// Returns the projection of this vector onto the plane with the given
+    /// normal, e.g. to slide a velocity along a surface. The normal does not
+    /// need to be normalized.
+    pub fn project_onto_plane(&self, normal: &Self) -> Self {
+        self.reject_from(normal)
+    }
+
+    /// Returns this velocity with the components that point into any of the
+    /// given contact surfaces removed, so that it slides along them, e.g. to
+    /// move a kinematic character along walls and floors. The normals point
+    /// out of the surfaces and do not need to be normalized.
+    ///
+    /// The velocity is first projected onto the plane of each surface it
+    /// moves into, then onto the crease of each pair of surfaces. The first
+    /// result that moves into none of the surfaces is returned. If there is
+    /// none, e.g. in a corner of three walls, the zero vector is returned.
+    pub fn slide_along_surfaces(&self, normals: &[Vector3]) -> Self {
+        const TOLERANCE: f32 = 1e-5;
+
+        let is_blocked = |v: &Vector3| {
+            normals
+                .iter()
+                .any(|n| v.dot(n) < -TOLERANCE * v.length() * n.length())
+        };
+
+        if !is_blocked(self) {
+            return *self;
+        }
+
+        let planes = normals
+            .iter()
+            .filter(|n| self.dot(n) < 0.0)
+            .map(|n| self.project_onto_plane(n));
+
+        let creases = normals.iter().enumerate().flat_map(|(i, a)| {
+            normals[i + 1..]
+                .iter()
+                .map(move |b| a.cross(b))
+                .filter(|crease| crease.dot(crease) > 0.0)
+                .map(move |crease| self.project_onto(&crease))
+        });
+
+        planes
+            .chain(creases)
+            .find(|v| !is_blocked(v))
+            .unwrap_or_default()
+    }
+
+    /// Returns the unsigned angle in radians between this vector and another
+    /// vector, in `[0, π]`. Neither vector needs to be normalized.
+    ///
+    /// This uses the `atan2` of the cross and dot products, which is accurate
+    /// for nearly parallel vectors, unlike the `acos` of the dot product.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
+    /// Returns the signed angle in radians, in `[-π, π]`, of the rotation
+    /// about `axis` from this vector to another vector, as seen when looking
+    /// against `axis`. The angle is positive for counterclockwise rotations.
+    ///
+    /// The vectors are assumed to be perpendicular to `axis`, which does not
+    /// need to be normalized.
+    pub fn signed_angle_to(&self, other: &Self, axis: &Self) -> f32 {
+        let cross = self.cross(other);
+        let angle = cross.length().atan2(self.dot(other));
+
+        if cross.dot(axis) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Creates a vector from spherical coordinates in the Y-up coordinate
+    /// system of WebGPU: `theta` is the polar angle in radians from the +Y
+    /// axis, and `phi` is the azimuthal angle in radians around the +Y axis,
+    /// measured from the +Z axis towards the +X axis.
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Self {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        Self {
+            x: radius * sin_theta * sin_phi,
+            y: radius * cos_theta,
+            z: radius * sin_theta * cos_phi,
+        }
+    }
+
+    /// Returns the spherical coordinates `(radius, theta, phi)` of this vector,
+    /// using the convention of [`Vector3::from_spherical`]. `theta` is in
+    /// `[0, π]` and `phi` is in `[-π, π]`. The zero vector returns all zeros.
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = self.length();
+
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let theta = (self.y / radius).clamp(-1.0, 1.0).acos();
+        let phi = self.x.atan2(self.z);
+
+        (radius, theta, phi)
+    }
+
+    /// Creates a vector from cylindrical coordinates around the +Y axis:
+    /// `radius` is the distance from the axis, `theta` is the angle in radians
+    /// around the axis, measured from the +Z axis towards the +X axis as in
+    /// [`Vector3::from_spherical`], and `y` is the height.
+    pub fn from_cylindrical(radius: f32, theta: f32, y: f32) -> Self {
+        let (sin, cos) = theta.sin_cos();
+
+        Self {
+            x: radius * sin,
+            y,
+            z: radius * cos,
+        }
+    }
+
+    /// Returns the cylindrical coordinates `(radius, theta, y)` of this vector,
+    /// using the convention of [`Vector3::from_cylindrical`]. `theta` is in
+    /// `[-π, π]`, and is 0 for points on the axis.
+    pub fn to_cylindrical(&self) -> (f32, f32, f32) {
+        (self.x.hypot(self.z), self.x.atan2(self.z), self.y)
+    }
+
+    /// Returns the point halfway between this point and another point.
+    pub fn midpoint(&self, other: &Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+
+    /// Returns the centroid, i.e. the average, of the given points, e.g. the
+    /// center of a face or a pivot point for a selection.
+    ///
+    /// The sum is accumulated in double precision so that large point sets do
+    /// not lose precision. Returns the zero vector if there are no points.
+    pub fn centroid(points: &[Vector3]) -> Self {
+        if points.is_empty() {
+            return Self::default();
+        }
+
+        let sum = points.iter().fold([0.0f64; 3], |[x, y, z], p| {
+            [x + p.x as f64, y + p.y as f64, z + p.z as f64]
+        });
+        let n = points.len() as f64;
+
+        Self {
+            x: (sum[0] / n) as f32,
+            y: (sum[1] / n) as f32,
+            z: (sum[2] / n) as f32,
+        }
+    }
+
+    /// Returns `self * a + b` computed component-wise with [`f32::mul_add`],
+    /// i.e. with a single rounding per component. This is more precise than
+    /// separate operations and faster on targets with hardware fused
+    /// multiply-add.
+    pub fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        Self {
+            x: self.x.mul_add(a.x, b.x),
+            y: self.y.mul_add(a.y, b.y),
+            z: self.z.mul_add(a.z, b.z),
+        }
+    }
+
+    /// Returns the linear interpolation between this vector and `other` by the
+    /// factor `t`, where `t = 0.0` returns this vector and `t = 1.0` returns
+    /// `other`. `t` is not clamped.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.zip_with(other, |a, b| a + (b - a) * t)
+    }
+
+    /// Returns this vector moved towards `target` by at most `max_delta`,
+    /// without overshooting, e.g. to move an object at a constant speed.
+    pub fn move_towards(&self, target: &Self, max_delta: f32) -> Self {
+        let delta = target - self;
+        let distance = delta.length();
+
+        if distance <= max_delta || distance == 0.0 {
+            *target
+        } else {
+            self + delta * (max_delta / distance)
+        }
+    }
+
+    /// Returns a unit vector perpendicular to this unit vector. The result is
+    /// arbitrary and flips discontinuously where `self.z` changes sign.
+    ///
+    /// See [`Self::any_orthonormal_pair`].
+    pub fn any_orthonormal_vector(&self) -> Self {
+        self.any_orthonormal_pair().0
+    }
+
+    /// Returns two unit vectors that are perpendicular to this unit vector and
+    /// to each other, such that `(a, b, self)` is right-handed, i.e.
+    /// `a.cross(&b) == self`. This builds a tangent frame from a normal, e.g.
+    /// for hemisphere sampling or decal projection.
+    ///
+    /// The implementation is branchless and based on [Duff et al., "Building
+    /// an Orthonormal Basis, Revisited"][onb] (2017).
+    ///
+    /// [onb]: https://jcgt.org/published/0006/01/01/
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            (1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x).into(),
+            (b, sign + self.y * self.y * a, -self.y).into(),
+        )
+    }
+
+    /// Returns a random direction uniformly distributed over the cone around
+    /// this direction with the given half-angle in radians, e.g. to spawn
+    /// particles or to sample soft shadow rays. This direction must be
+    /// normalized.
+    ///
+    /// `rng` must return independent random numbers uniformly distributed in
+    /// `[0, 1)`. It is called twice.
+    pub fn random_in_cone(&self, half_angle: f32, mut rng: impl FnMut() -> f32) -> Self {
+        let cos_theta = 1.0 - rng() * (1.0 - half_angle.cos());
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let (sin_phi, cos_phi) = (TAU * rng()).sin_cos();
+        let (a, b) = self.any_orthonormal_pair();
+
+        a * (sin_theta * cos_phi) + b * (sin_theta * sin_phi) + self * cos_theta
+    }
+
+    /// Returns this direction with a random offset of length up to `spread`
+    /// perpendicular to it, normalized, e.g. to add inaccuracy to
+    /// projectiles. This direction must be normalized.
+    ///
+    /// This is cheaper than [`Vector3::random_in_cone`], but the directions
+    /// are not uniformly distributed over the solid angle. A spread of `1.0`
+    /// deviates by up to 45 degrees.
+    ///
+    /// `rng` must return independent random numbers uniformly distributed in
+    /// `[0, 1)`. It is called twice.
+    pub fn jittered(&self, spread: f32, mut rng: impl FnMut() -> f32) -> Self {
+        let radius = spread * rng().sqrt();
+        let (sin_phi, cos_phi) = (TAU * rng()).sin_cos();
+        let (a, b) = self.any_orthonormal_pair();
+
+        (self + a * (radius * cos_phi) + b * (radius * sin_phi)).normalized()
+    }
+
+    /// Returns the smallest component of this vector.
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Returns the largest component of this vector, e.g. the longest extent
+    /// of a bounding box.
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Returns whether every component of this vector differs from the
+    /// matching component of `other` by at most `epsilon`, e.g. to compare
+    /// results in tests. Vectors with NaN components are never equal.
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+
+    /// Returns the axis of the component with the largest absolute value, e.g.
+    /// the dominant axis for triplanar mapping. Ties are broken in X, Y, Z
+    /// order.
+    pub fn major_axis(&self) -> Axis {
+        let x = self.x.abs();
+        let y = self.y.abs();
+        let z = self.z.abs();
+
+        if x >= y && x >= z {
+            Axis::X
+        } else if y >= z {
+            Axis::Y
+        } else {
+            Axis::Z
+        }
+    }
+
+    /// Returns the axis of the component with the smallest absolute value.
+    /// Ties are broken in X, Y, Z order.
+    pub fn minor_axis(&self) -> Axis {
+        let x = self.x.abs();
+        let y = self.y.abs();
+        let z = self.z.abs();
+
+        if x <= y && x <= z {
+            Axis::X
+        } else if y <= z {
+            Axis::Y
+        } else {
+            Axis::Z
+        }
+    }
+
+    /// Returns the x and y components of this vector, like `v.xy` in a shader.
+    pub fn xy(&self) -> Vector2 {
+        Vector2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    /// Returns the x and z components of this vector, e.g. the position on the
+    /// ground plane in a Y-up scene.
+    pub fn xz(&self) -> Vector2 {
+        Vector2 {
+            x: self.x,
+            y: self.z,
+        }
+    }
+
+    /// Returns the y and z components of this vector.
+    pub fn yz(&self) -> Vector2 {
+        Vector2 {
+            x: self.y,
+            y: self.z,
+        }
+    }
+
+    /// Returns this vector with its components in reverse order, e.g. to
+    /// convert between RGB and BGR.
+    pub fn zyx(&self) -> Self {
+        Self {
+            x: self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    /// Returns this vector extended with a w component of 0, i.e. as a
+    /// direction in homogeneous coordinates.
+    pub fn xyz0(&self) -> Vector4 {
+        Vector4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: 0.0,
+        }
+    }
+
+    /// Returns this vector extended with a w component of 1, i.e. as a point
+    /// in homogeneous coordinates.
+    pub fn xyz1(&self) -> Vector4 {
+        Vector4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: 1.0,
+        }
+    }
+
+    /// Returns this vector rotated by the given angle in radians around the
+    /// given axis, using [Rodrigues' rotation formula][rodrigues]. The axis
+    /// must be normalized.
+    ///
+    /// [rodrigues]: https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula
+    pub fn rotate_about_axis(&self, axis: &Vector3, angle: f32) -> Self {
+        let cos = angle.cos();
+        let sin = angle.sin();
+
+        self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (1.0 - cos))
+    }
+
+    /// Splits the given vectors into separate arrays of x, y, and z components
+    /// (array-of-structs to struct-of-arrays).
+    pub fn to_soa(vectors: &[Vector3]) -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+        (
+            vectors.iter().map(|v| v.x).collect(),
+            vectors.iter().map(|v| v.y).collect(),
+            vectors.iter().map(|v| v.z).collect(),
+        )
+    }
+
+    /// Combines separate arrays of x, y, and z components into vectors
+    /// (struct-of-arrays to array-of-structs).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    pub fn from_soa(x: &[f32], y: &[f32], z: &[f32]) -> Vec<Vector3> {
+        assert!(
+            x.len() == y.len() && y.len() == z.len(),
+            "component arrays must have the same length"
+        );
+
+        x.iter()
+            .zip(y)
+            .zip(z)
+            .map(|((&x, &y), &z)| Vector3 { x, y, z })
+            .collect()
+    }
+
+    /// Writes the given vectors into an interleaved buffer, e.g. a vertex
+    /// buffer, leaving the other attributes untouched.
+    ///
+    /// The `i`-th vector is written to `buffer[i * stride + offset..]`, where
+    /// `stride` is the number of floats per vertex and `offset` is the index of
+    /// the vector's first component within a vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is less than `offset + 3` or if the buffer is too
+    /// small to hold all vectors.
+    pub fn interleave(vectors: &[Vector3], buffer: &mut [f32], stride: usize, offset: usize) {
+        assert!(
+            stride >= offset + 3,
+            "stride must leave room for three components after the offset"
+        );
+        assert!(
+            buffer.len() >= vectors.len() * stride,
+            "buffer is too small to hold all vectors"
+        );
+
+        for (vertex, v) in buffer.chunks_exact_mut(stride).zip(vectors) {
+            vertex[offset] = v.x;
+            vertex[offset + 1] = v.y;
+            vertex[offset + 2] = v.z;
+        }
+    }
+
+    /// Reads vectors from an interleaved buffer, e.g. a vertex buffer. This is
+    /// the inverse of [`Self::interleave`].
+    ///
+    /// One vector is read per whole vertex of `stride` floats in the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is less than `offset + 3`.
+    pub fn deinterleave(buffer: &[f32], stride: usize, offset: usize) -> Vec<Vector3> {
+        assert!(
+            stride >= offset + 3,
+            "stride must leave room for three components after the offset"
+        );
+
+        buffer
+            .chunks_exact(stride)
+            .map(|vertex| Vector3 {
+                x: vertex[offset],
+                y: vertex[offset + 1],
+                z: vertex[offset + 2],
+            })
+            .collect()
+    }
+
+    /// Returns the skew-symmetric cross product matrix of this vector, i.e.
+    /// the matrix `m` such that `m * v == self.cross(&v)` for any vector `v`.
+    #[rustfmt::skip]
+    pub fn cross_matrix(&self) -> Matrix3 {
+        Matrix3::new(
+            0.0, -self.z, self.y,
+            self.z, 0.0, -self.x,
+            -self.y, self.x, 0.0,
+        )
+    }
+
+    /// Returns this normal vector transformed by the given transformation
+    /// matrix and normalized.
+    ///
+    /// Transforming a normal by the matrix itself skews it under non-uniform
+    /// scale, so the transpose of the [adjugate](Matrix3::adjugate) of the
+    /// upper-left 3x3 matrix is applied instead. Unlike the inverse
+    /// transpose, this also works for singular matrices. Normals keep facing
+    /// outwards under mirroring transformations.
+    ///
+    /// Returns the zero vector if the normal collapses, e.g. when scaling by
+    /// zero along two axes.
+    pub fn transform_normal(&self, m: &Matrix4) -> Self {
+        let m3 = Matrix3::from_matrix4(m);
+        let cofactor = m3.adjugate().transpose();
+
+        let normal = if m3.determinant() < 0.0 {
+            -(cofactor * self)
+        } else {
+            cofactor * self
+        };
+
+        normal.normalize_or_zero()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use core::f32::consts;
+
     use assert_float_eq::assert_float_absolute_eq;
 
     use super::*;
 
+    #[test]
+    fn test_display_and_from_str() {
+        let v = Vector3::from((1.0, -2.5, 0.125));
+
+        assert_eq!(v.to_string(), "(1, -2.5, 0.125)");
+        assert_eq!(format!("{v:.2}"), "(1.00, -2.50, 0.12)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+        assert_eq!("1, -2.5, 0.125".parse(), Ok(v));
+        assert!("(1, 2)".parse::<Vector3>().is_err());
+    }
+
+    #[test]
+    fn test_sum() {
+        let points: [Vector3; 3] = [
+            (1.0, 2.0, 3.0).into(),
+            (4.0, 5.0, 6.0).into(),
+            (-1.0, 0.0, 1.0).into(),
+        ];
+
+        assert_eq!(points.iter().sum::<Vector3>(), (4.0, 7.0, 10.0).into());
+        assert_eq!(points.into_iter().sum::<Vector3>(), (4.0, 7.0, 10.0).into());
+        assert_eq!(
+            std::iter::empty::<Vector3>().sum::<Vector3>(),
+            Vector3::default()
+        );
+    }
+
     #[test]
     fn test_set() {
         let mut v = Vector3::default();
@@ -244,6 +1124,68 @@ mod tests {
         assert_eq!(v.z, 3.0);
     }
 
+    #[test]
+    fn test_try_from_iter() {
+        let mut floats = [1.0, 2.0, 3.0, 4.0, 5.0].into_iter();
+
+        assert_eq!(
+            Vector3::try_from_iter(&mut floats),
+            Ok((1.0, 2.0, 3.0).into())
+        );
+        assert_eq!(
+            Vector3::try_from_iter(&mut floats),
+            Err(NotEnoughElementsError {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_component_wise() {
+        let a = Vector3::from((1.0, -2.0, 0.0));
+        let b = Vector3::from((-1.0, 3.0, 0.5));
+
+        assert_eq!(a.min(&b), (-1.0, -2.0, 0.0).into());
+        assert_eq!(a.max(&b), (1.0, 3.0, 0.5).into());
+        assert_eq!(
+            a.clamp(&(0.0, -1.0, -1.0).into(), &(0.5, 1.0, 1.0).into()),
+            (0.5, -1.0, 0.0).into()
+        );
+        assert_eq!(a.abs(), (1.0, 2.0, 0.0).into());
+        assert_eq!(a.signum(), (1.0, -1.0, 0.0).into());
+    }
+
+    #[test]
+    fn test_rounding() {
+        let v = Vector3::from((1.25, -1.25, 2.5));
+
+        assert_eq!(v.floor(), (1.0, -2.0, 2.0).into());
+        assert_eq!(v.ceil(), (2.0, -1.0, 3.0).into());
+        assert_eq!(v.round(), (1.0, -1.0, 3.0).into());
+        assert_eq!(v.fract(), (0.25, 0.75, 0.5).into());
+    }
+
+    #[test]
+    fn test_index() {
+        let mut v = Vector3::from((1.0, 2.0, 3.0));
+
+        assert_eq!([v[0], v[1], v[2]], [1.0, 2.0, 3.0]);
+        assert_eq!([v[Axis::X], v[Axis::Y], v[Axis::Z]], [1.0, 2.0, 3.0]);
+
+        v[0] = 4.0;
+        v[Axis::Z] = 6.0;
+
+        assert_eq!(v, (4.0, 2.0, 6.0).into());
+        assert_eq!(v[v.major_axis()], 6.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let _ = Vector3::default()[3];
+    }
+
     #[test]
     fn test_length() {
         let v = Vector3 {
@@ -257,6 +1199,38 @@ mod tests {
         assert_float_absolute_eq!(v.length(), expected);
     }
 
+    #[test]
+    fn test_distance() {
+        let a = Vector3::from((1.0, 2.0, 3.0));
+        let b = Vector3::from((4.0, 6.0, 3.0));
+
+        assert_eq!(a.distance_squared(&b), 25.0);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(b.distance(&a), 5.0);
+        assert_eq!(a.distance(&a), 0.0);
+    }
+
+    #[test]
+    fn test_length_squared() {
+        let v = Vector3::from((2.0, 3.0, 6.0));
+
+        assert_eq!(v.length_squared(), 49.0);
+        assert_eq!(v.length(), 7.0);
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        let v = Vector3::from((0.0, 3.0, 4.0));
+
+        assert_eq!(v.try_normalize(), Some((0.0, 0.6, 0.8).into()));
+        assert_eq!(Vector3::default().try_normalize(), None);
+        assert_eq!(
+            Vector3::from((f32::INFINITY, 0.0, 0.0)).try_normalize(),
+            None
+        );
+        assert_eq!(Vector3::from((f32::NAN, 0.0, 0.0)).try_normalize(), None);
+    }
+
     #[test]
     fn test_normalize() {
         let test_values = [
@@ -312,6 +1286,187 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize_or() {
+        let v = Vector3 {
+            x: 0.0,
+            y: 3.0,
+            z: 4.0,
+        };
+        let fallback = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+
+        let actual = v.normalize_or(fallback);
+
+        assert_float_absolute_eq!(actual.x, 0.0);
+        assert_float_absolute_eq!(actual.y, 0.6);
+        assert_float_absolute_eq!(actual.z, 0.8);
+
+        assert_eq!(Vector3::default().normalize_or(fallback), fallback);
+        assert_eq!(Vector3::default().normalize_or_zero(), Vector3::default());
+
+        let infinite = Vector3 {
+            x: f32::INFINITY,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        assert_eq!(infinite.normalize_or_zero(), Vector3::default());
+    }
+
+    #[test]
+    fn test_projection() {
+        let v = Vector3::from((3.0, 4.0, 5.0));
+        let axis = Vector3::from((0.0, 2.0, 0.0));
+
+        assert_eq!(v.project_onto(&axis), (0.0, 4.0, 0.0).into());
+        assert_eq!(v.reject_from(&axis), (3.0, 0.0, 5.0).into());
+        assert_eq!(v.project_onto_plane(&axis), (3.0, 0.0, 5.0).into());
+        assert_eq!(v.project_onto(&Vector3::default()), Vector3::default());
+        assert_eq!(v.reject_from(&Vector3::default()), v);
+    }
+
+    #[test]
+    fn test_slide_along_surfaces() {
+        let floor = Vector3::from((0.0, 1.0, 0.0));
+        let wall = Vector3::from((-1.0, 0.0, 0.0));
+        let back = Vector3::from((0.0, 0.0, 2.0));
+        let velocity = Vector3::from((1.0, -1.0, -1.0));
+
+        assert_eq!(velocity.slide_along_surfaces(&[]), velocity);
+        assert_eq!(
+            velocity.slide_along_surfaces(&[-floor]),
+            velocity,
+            "moving away from a surface is not blocked"
+        );
+        assert_eq!(
+            velocity.slide_along_surfaces(&[floor]),
+            (1.0, 0.0, -1.0).into()
+        );
+        assert_eq!(
+            velocity.slide_along_surfaces(&[floor, wall]),
+            (0.0, 0.0, -1.0).into()
+        );
+        assert_eq!(
+            velocity.slide_along_surfaces(&[floor, wall, back]),
+            Vector3::default()
+        );
+
+        // Projecting onto either plane of an acute crease moves into the other
+        // plane, so the velocity follows the crease.
+        let a = Vector3::from((1.0, 1.0, 0.0));
+        let b = Vector3::from((-1.0, 1.0, 0.0));
+        let slid = Vector3::from((0.0, -1.0, 1.0)).slide_along_surfaces(&[a, b]);
+
+        assert_float_absolute_eq!(slid.x, 0.0);
+        assert_float_absolute_eq!(slid.y, 0.0);
+        assert_float_absolute_eq!(slid.z, 1.0);
+    }
+
+    #[test]
+    fn test_any_orthonormal_pair() {
+        let normals: [Vector3; 4] = [
+            (0.0, 0.0, 1.0).into(),
+            (0.0, 0.0, -1.0).into(),
+            (0.6, 0.0, 0.8).into(),
+            (0.48, -0.6, -0.64).into(),
+        ];
+
+        for n in normals {
+            let (a, b) = n.any_orthonormal_pair();
+            let cross = a.cross(&b);
+
+            assert_float_absolute_eq!(a.length(), 1.0);
+            assert_float_absolute_eq!(b.length(), 1.0);
+            assert_float_absolute_eq!(a.dot(&n), 0.0);
+            assert_float_absolute_eq!(b.dot(&n), 0.0);
+            assert_float_absolute_eq!(cross.x, n.x);
+            assert_float_absolute_eq!(cross.y, n.y);
+            assert_float_absolute_eq!(cross.z, n.z);
+            assert_eq!(n.any_orthonormal_vector(), a);
+        }
+    }
+
+    #[test]
+    fn test_angle_to() {
+        let x = Vector3::from((2.0, 0.0, 0.0));
+        let y = Vector3::from((0.0, 3.0, 0.0));
+        let z = Vector3::from((0.0, 0.0, 1.0));
+
+        assert_float_absolute_eq!(x.angle_to(&y), consts::FRAC_PI_2);
+        assert_float_absolute_eq!(x.angle_to(&-x), consts::PI);
+        assert_float_absolute_eq!(x.angle_to(&x), 0.0);
+        assert_float_absolute_eq!(x.angle_to(&(1.0, 1e-4, 0.0).into()), 1e-4, 1e-9);
+
+        assert_float_absolute_eq!(x.signed_angle_to(&y, &z), consts::FRAC_PI_2);
+        assert_float_absolute_eq!(y.signed_angle_to(&x, &z), -consts::FRAC_PI_2);
+        assert_float_absolute_eq!(x.signed_angle_to(&y, &-z), -consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_midpoint_and_centroid() {
+        let a = Vector3::from((1.0, 2.0, 3.0));
+        let b = Vector3::from((3.0, -2.0, 4.0));
+
+        assert_eq!(a.midpoint(&b), (2.0, 0.0, 3.5).into());
+        assert_eq!(
+            Vector3::centroid(&[a, b, (-1.0, 3.0, 2.0).into()]),
+            (1.0, 1.0, 3.0).into()
+        );
+        assert_eq!(Vector3::centroid(&[]), Vector3::default());
+
+        // A naive f32 sum loses the small value next to the large ones.
+        let points: [Vector3; 3] = [
+            (1e8, 0.0, 0.0).into(),
+            (3.0, 0.0, 0.0).into(),
+            (-1e8, 0.0, 0.0).into(),
+        ];
+
+        assert_eq!(Vector3::centroid(&points).x, 1.0);
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let v = Vector3::from((1.0, 2.0, 3.0));
+        let a = Vector3::from((2.0, -1.0, 0.5));
+        let b = Vector3::from((0.5, 0.5, 0.5));
+
+        assert_eq!(v.mul_add(&a, &b), (2.5, -1.5, 2.0).into());
+
+        // The product is not rounded before the addition.
+        let x = 1.0 + f32::EPSILON;
+        let v = Vector3::from((x, x, x));
+        let c = -(x * x);
+        let fused = v.mul_add(&v, &(c, c, c).into());
+
+        assert_eq!(fused.x, f32::EPSILON * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vector3::from((1.0, 2.0, 3.0));
+        let b = Vector3::from((3.0, -2.0, 3.0));
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), (2.0, 0.0, 3.0).into());
+        assert_eq!(a.lerp(&b, 2.0), (5.0, -6.0, 3.0).into());
+    }
+
+    #[test]
+    fn test_move_towards() {
+        let a = Vector3::from((1.0, 1.0, 1.0));
+        let b = Vector3::from((4.0, 5.0, 1.0));
+
+        assert_eq!(a.move_towards(&b, 2.5), (2.5, 3.0, 1.0).into());
+        assert_eq!(a.move_towards(&b, 5.0), b);
+        assert_eq!(a.move_towards(&b, 10.0), b);
+        assert_eq!(a.move_towards(&a, 1.0), a);
+    }
+
     #[test]
     fn test_dot() {
         let a = Vector3 {
@@ -357,4 +1512,276 @@ mod tests {
         assert_float_absolute_eq!(actual.y, expected.y);
         assert_float_absolute_eq!(actual.z, expected.z);
     }
+
+    #[test]
+    fn test_cross_matrix() {
+        let a = Vector3 {
+            x: 2.0,
+            y: 3.0,
+            z: 4.0,
+        };
+
+        let b = Vector3 {
+            x: 2.0,
+            y: -3.0,
+            z: 4.0,
+        };
+
+        assert_eq!(a.cross_matrix() * b, a.cross(&b));
+        assert_eq!(a.cross_matrix().transpose(), (-a).cross_matrix());
+    }
+
+    #[test]
+    fn test_transform_normal() {
+        // The normal of the plane x + y = 1 under a non-uniform scale.
+        let normal = Vector3::from((1.0, 1.0, 0.0)).normalized();
+        let m = Matrix4::from_scale(&(2.0, 1.0, 1.0).into());
+        let actual = normal.transform_normal(&m);
+        let expected = Vector3::from((1.0, 2.0, 0.0)).normalized();
+
+        assert_float_absolute_eq!(actual.x, expected.x);
+        assert_float_absolute_eq!(actual.y, expected.y);
+        assert_float_absolute_eq!(actual.z, expected.z);
+
+        // Mirroring flips the normal along with the surface.
+        let mirror = Matrix4::from_scale(&(-1.0, 1.0, 1.0).into());
+
+        assert_eq!(
+            Vector3::from((1.0, 0.0, 0.0)).transform_normal(&mirror),
+            (-1.0, 0.0, 0.0).into()
+        );
+
+        // Singular matrices are fine as long as the normal survives.
+        let flatten = Matrix4::from_scale(&(1.0, 1.0, 0.0).into());
+
+        assert_eq!(
+            Vector3::from((0.0, 0.0, 1.0)).transform_normal(&flatten),
+            (0.0, 0.0, 1.0).into()
+        );
+        assert_eq!(
+            Vector3::from((1.0, 0.0, 0.0)).transform_normal(&flatten),
+            Vector3::default()
+        );
+    }
+
+    #[test]
+    fn test_rotate_about_axis() {
+        let v = Vector3 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        let actual = v.rotate_about_axis(&(0.0, 0.0, 1.0).into(), std::f32::consts::FRAC_PI_2);
+
+        assert_float_absolute_eq!(actual.x, 0.0);
+        assert_float_absolute_eq!(actual.y, 1.0);
+        assert_float_absolute_eq!(actual.z, 0.0);
+
+        let axis = Vector3 {
+            x: 1.0,
+            y: 1.0,
+            z: 1.0,
+        }
+        .normalized();
+        let actual = v.rotate_about_axis(&axis, 2.0 * std::f32::consts::FRAC_PI_3);
+
+        assert_float_absolute_eq!(actual.x, 0.0);
+        assert_float_absolute_eq!(actual.y, 1.0);
+        assert_float_absolute_eq!(actual.z, 0.0);
+    }
+
+    #[test]
+    fn test_soa() {
+        let vectors: Vec<Vector3> = vec![(1.0, 2.0, 3.0).into(), (4.0, 5.0, 6.0).into()];
+
+        let (x, y, z) = Vector3::to_soa(&vectors);
+
+        assert_eq!(x, [1.0, 4.0]);
+        assert_eq!(y, [2.0, 5.0]);
+        assert_eq!(z, [3.0, 6.0]);
+        assert_eq!(Vector3::from_soa(&x, &y, &z), vectors);
+    }
+
+    #[test]
+    fn test_interleave() {
+        let vectors: Vec<Vector3> = vec![(1.0, 2.0, 3.0).into(), (4.0, 5.0, 6.0).into()];
+        let mut buffer = [0.0; 10];
+
+        Vector3::interleave(&vectors, &mut buffer, 5, 2);
+
+        assert_eq!(buffer, [0.0, 0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 4.0, 5.0, 6.0]);
+        assert_eq!(Vector3::deinterleave(&buffer, 5, 2), vectors);
+        assert_eq!(Vector3::deinterleave(&buffer[..9], 5, 2), &vectors[..1]);
+    }
+
+    #[test]
+    fn test_min_and_max_element() {
+        let v = Vector3::from((2.0, -3.0, 1.0));
+
+        assert_eq!(v.min_element(), -3.0);
+        assert_eq!(v.max_element(), 2.0);
+    }
+
+    #[test]
+    fn test_abs_diff_eq() {
+        let a = Vector3::from((1.0, 2.0, 3.0));
+
+        assert!(a.abs_diff_eq(&(1.0005, 1.9995, 3.0).into(), 1e-3));
+        assert!(!a.abs_diff_eq(&(1.0, 2.0, 3.01).into(), 1e-3));
+        assert!(!a.abs_diff_eq(&(f32::NAN, 2.0, 3.0).into(), 1e-3));
+    }
+
+    #[test]
+    fn test_major_and_minor_axis() {
+        let v = Vector3 {
+            x: 1.0,
+            y: -3.0,
+            z: 2.0,
+        };
+
+        assert_eq!(v.major_axis(), Axis::Y);
+        assert_eq!(v.minor_axis(), Axis::X);
+
+        let v = Vector3 {
+            x: -1.0,
+            y: 0.5,
+            z: -4.0,
+        };
+
+        assert_eq!(v.major_axis(), Axis::Z);
+        assert_eq!(v.minor_axis(), Axis::Y);
+
+        assert_eq!(Vector3::default().major_axis(), Axis::X);
+        assert_eq!(Vector3::default().minor_axis(), Axis::X);
+    }
+
+    #[test]
+    fn test_map_and_zip_with() {
+        let a = Vector3 {
+            x: 1.5,
+            y: -2.5,
+            z: 3.0,
+        };
+        let b = Vector3 {
+            x: 2.0,
+            y: -3.0,
+            z: 1.0,
+        };
+
+        assert_eq!(a.map(f32::floor), (1.0, -3.0, 3.0).into());
+        assert_eq!(a.zip_with(&b, f32::max), (2.0, -2.5, 3.0).into());
+    }
+
+    #[test]
+    fn test_swizzles() {
+        let v = Vector3::from((1.0, 2.0, 3.0));
+
+        assert_eq!(v.xy(), (1.0, 2.0).into());
+        assert_eq!(v.xz(), (1.0, 3.0).into());
+        assert_eq!(v.yz(), (2.0, 3.0).into());
+        assert_eq!(v.zyx(), (3.0, 2.0, 1.0).into());
+        assert_eq!(v.xyz0(), (1.0, 2.0, 3.0, 0.0).into());
+        assert_eq!(v.xyz1(), (1.0, 2.0, 3.0, 1.0).into());
+    }
+
+    #[test]
+    fn test_spherical() {
+        let v = Vector3::from_spherical(2.0, consts::FRAC_PI_2, consts::FRAC_PI_2);
+
+        assert!(v.abs_diff_eq(&(2.0, 0.0, 0.0).into(), 1e-6));
+        assert!(Vector3::from_spherical(1.0, 0.0, 1.0).abs_diff_eq(&(0.0, 1.0, 0.0).into(), 1e-6));
+
+        let v = Vector3::from((-1.0, 2.0, -3.0));
+        let (radius, theta, phi) = v.to_spherical();
+
+        assert!(Vector3::from_spherical(radius, theta, phi).abs_diff_eq(&v, 1e-5));
+        assert_eq!(Vector3::default().to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_cylindrical() {
+        let v = Vector3::from_cylindrical(2.0, consts::FRAC_PI_2, 3.0);
+
+        assert!(v.abs_diff_eq(&(2.0, 3.0, 0.0).into(), 1e-6));
+
+        let v = Vector3::from((-1.0, 2.0, -3.0));
+        let (radius, theta, y) = v.to_cylindrical();
+
+        assert!(Vector3::from_cylindrical(radius, theta, y).abs_diff_eq(&v, 1e-5));
+        assert_eq!(
+            Vector3::from((0.0, 5.0, 0.0)).to_cylindrical(),
+            (0.0, 0.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        const UP: Vector3 = Vector3::Y;
+
+        assert_eq!(UP, (0.0, 1.0, 0.0).into());
+        assert_eq!(Vector3::ZERO, Vector3::default());
+        assert_eq!(Vector3::ONE, (1.0, 1.0, 1.0).into());
+        assert_eq!(Vector3::X.cross(&Vector3::Y), Vector3::Z);
+        assert_eq!(-Vector3::Z, Vector3::NEG_Z);
+        assert_eq!(Vector3::NEG_X + Vector3::NEG_Y, (-1.0, -1.0, 0.0).into());
+        assert_eq!(Matrix4::IDENTITY.elements, Matrix4::identity().elements);
+        assert_eq!(Matrix3::IDENTITY, Matrix3::identity());
+    }
+
+    #[test]
+    fn test_clamp_length_and_with_length() {
+        let v = Vector3::from((3.0, 0.0, 4.0));
+
+        assert_eq!(v.with_length(10.0), (6.0, 0.0, 8.0).into());
+        assert_eq!(v.clamp_length(2.5), (1.5, 0.0, 2.0).into());
+        assert_eq!(v.clamp_length(6.0), v);
+        assert_eq!(v.clamp_length_between(10.0, 20.0), (6.0, 0.0, 8.0).into());
+        assert_eq!(v.clamp_length_between(1.0, 2.5), (1.5, 0.0, 2.0).into());
+        assert_eq!(v.clamp_length_between(1.0, 6.0), v);
+
+        assert_eq!(Vector3::ZERO.with_length(2.0), Vector3::ZERO);
+        assert_eq!(Vector3::ZERO.clamp_length(2.0), Vector3::ZERO);
+        assert_eq!(Vector3::ZERO.clamp_length_between(1.0, 2.0), Vector3::ZERO);
+    }
+
+    #[test]
+    fn test_random_in_cone_and_jittered() {
+        // A linear congruential generator is enough to spread the samples.
+        let mut state = 12345u32;
+        let mut rng = || {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+
+        let direction = Vector3::from((1.0, 2.0, -2.0)).normalized();
+        let half_angle = 0.3;
+        let mut sum = Vector3::ZERO;
+
+        for _ in 0..1000 {
+            let v = direction.random_in_cone(half_angle, &mut rng);
+
+            assert!((v.length() - 1.0).abs() < 1e-5);
+            assert!(v.angle_to(&direction) <= half_angle + 1e-5);
+            sum += v;
+        }
+
+        assert!(sum.normalized().angle_to(&direction) < 0.01);
+
+        for _ in 0..1000 {
+            let v = direction.jittered(0.1, &mut rng);
+
+            assert!((v.length() - 1.0).abs() < 1e-5);
+            assert!(v.angle_to(&direction) <= 0.1f32.atan() + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_cross_antisymmetric() {
+        let v = Vector3::from((0.1, 0.7, -0.3));
+        let w = Vector3::from((1.3, -0.2, 0.9));
+
+        assert_eq!(v.cross(&v), Vector3::ZERO);
+        assert_eq!(v.cross(&w) + w.cross(&v), Vector3::ZERO);
+    }
 }
@@ -0,0 +1,190 @@
+use std::{fmt, str::FromStr};
+
+use crate::format::{parse_components, write_components};
+
+use super::{ParseError, Vector2, Vector3};
+
+/// 4D vector for quantities such as homogeneous coordinates and matrix
+/// columns.
+///
+/// You can convert a tuple or an array of four floats to a 4D vector using
+/// `.into()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Vector4 {
+    /// The x component.
+    pub x: f32,
+    /// The y component.
+    pub y: f32,
+    /// The z component.
+    pub z: f32,
+    /// The w component.
+    pub w: f32,
+}
+
+unsafe impl Send for Vector4 {}
+unsafe impl Sync for Vector4 {}
+
+impl Eq for Vector4 {}
+
+impl fmt::Display for Vector4 {
+    /// Formats this vector as `(x, y, z, w)`.
+    ///
+    /// The precision, if any, applies to each component, e.g. `{:.2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_components(f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+impl FromStr for Vector4 {
+    type Err = ParseError;
+
+    /// Parses a vector in the format written by `Display`, e.g. `(x, y, z, w)`.
+    /// The parentheses are optional.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let [x, y, z, w] = parse_components::<f32, 4>(s)?;
+
+        Ok(Self { x, y, z, w })
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vector4 {
+    fn from(tuple: (f32, f32, f32, f32)) -> Self {
+        Vector4 {
+            x: tuple.0,
+            y: tuple.1,
+            z: tuple.2,
+            w: tuple.3,
+        }
+    }
+}
+
+impl From<[f32; 4]> for Vector4 {
+    fn from(array: [f32; 4]) -> Self {
+        Vector4 {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+            w: array[3],
+        }
+    }
+}
+
+impl Vector4 {
+    /// Sets the elements of this vector.
+    pub fn set(&mut self, x: f32, y: f32, z: f32, w: f32) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+        self.w = w;
+    }
+
+    /// Returns the x and y components of this vector, like `v.xy` in a shader.
+    pub fn xy(&self) -> Vector2 {
+        Vector2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    /// Returns the x, y, and z components of this vector, dropping w.
+    pub fn xyz(&self) -> Vector3 {
+        Vector3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Returns this vector with its components in reverse order.
+    pub fn wzyx(&self) -> Self {
+        Self {
+            x: self.w,
+            y: self.z,
+            z: self.y,
+            w: self.x,
+        }
+    }
+
+    /// Returns a vector with `f` applied to each component of this vector.
+    pub fn map(&self, mut f: impl FnMut(f32) -> f32) -> Self {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            w: f(self.w),
+        }
+    }
+
+    /// Returns a vector with `f` applied to each pair of corresponding
+    /// components of this vector and `other`.
+    pub fn zip_with(&self, other: &Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Self {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+            w: f(self.w, other.w),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        let expected = Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        };
+
+        assert_eq!(Vector4::from((1.0, 2.0, 3.0, 4.0)), expected);
+        assert_eq!(Vector4::from([1.0, 2.0, 3.0, 4.0]), expected);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let v = Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0,
+        };
+
+        assert_eq!(v.to_string(), "(1, 2, 3, 4)");
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    #[test]
+    fn test_set() {
+        let mut v = Vector4::default();
+
+        v.set(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+        assert_eq!(v.z, 3.0);
+        assert_eq!(v.w, 4.0);
+    }
+
+    #[test]
+    fn test_map_and_zip_with() {
+        let a: Vector4 = (1.0, -2.0, 3.0, -4.0).into();
+        let b: Vector4 = (4.0, 3.0, 2.0, 1.0).into();
+
+        assert_eq!(a.map(|x| x * 2.0), (2.0, -4.0, 6.0, -8.0).into());
+        assert_eq!(a.zip_with(&b, f32::min), (1.0, -2.0, 2.0, -4.0).into());
+    }
+
+    #[test]
+    fn test_swizzles() {
+        let v = Vector4::from((1.0, 2.0, 3.0, 4.0));
+
+        assert_eq!(v.xy(), (1.0, 2.0).into());
+        assert_eq!(v.xyz(), (1.0, 2.0, 3.0).into());
+        assert_eq!(v.wzyx(), (4.0, 3.0, 2.0, 1.0).into());
+    }
+}